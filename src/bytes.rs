@@ -0,0 +1,140 @@
+//! Module for byte size humanisation. See [`humanise_bytes`] and [`humanise_bytes_with`].
+
+/// The unit symbols for binary (1024-based) sizes, smallest first.
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+/// The unit symbols for decimal (1000-based) sizes, smallest first.
+const DECIMAL_UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Which units to use when humanising byte sizes. Used by [`humanise_bytes_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteUnits {
+    /// Binary (IEC) units, where each unit is 1024 times larger than the previous one (`KiB`, `MiB`, `GiB`, ...).
+    Binary,
+    /// Decimal (SI) units, where each unit is 1000 times larger than the previous one (`KB`, `MB`, `GB`, ...).
+    Decimal,
+}
+
+impl ByteUnits {
+    fn base(self) -> u64 {
+        match self {
+            ByteUnits::Binary => 1024,
+            ByteUnits::Decimal => 1000,
+        }
+    }
+
+    fn symbols(self) -> &'static [&'static str; 7] {
+        match self {
+            ByteUnits::Binary => &BINARY_UNITS,
+            ByteUnits::Decimal => &DECIMAL_UNITS,
+        }
+    }
+}
+
+/// Humanise a byte size using binary units with one decimal place.
+///
+/// See [`humanise_bytes_with`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_bytes;
+///
+/// assert_eq!(humanise_bytes(1536), "1.5 KiB");
+/// assert_eq!(humanise_bytes(3_355_443), "3.2 MiB");
+/// assert_eq!(humanise_bytes(4 * 1024 * 1024 * 1024), "4.0 GiB");
+/// assert_eq!(humanise_bytes(0), "0 B");
+/// ```
+pub fn humanise_bytes(bytes: u64) -> String {
+    humanise_bytes_with(bytes, ByteUnits::Binary, 1)
+}
+
+/// Humanise a byte size using decimal units with one decimal place.
+///
+/// See [`humanise_bytes_with`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_bytes_decimal;
+///
+/// assert_eq!(humanise_bytes_decimal(1500), "1.5 KB");
+/// assert_eq!(humanise_bytes_decimal(1_000_000), "1.0 MB");
+/// assert_eq!(humanise_bytes_decimal(999), "999 B");
+/// ```
+pub fn humanise_bytes_decimal(bytes: u64) -> String {
+    humanise_bytes_with(bytes, ByteUnits::Decimal, 1)
+}
+
+/// Humanise a byte size.
+///
+/// # Arguments
+///
+/// * `bytes`: The size in bytes.
+/// * `units`: Whether to use binary or decimal units.
+/// * `precision`: The number of decimal places to show.
+///
+/// # Return value
+///
+/// The size in the largest unit that fits in it, rounded to `precision` decimal places, followed by the unit's symbol.
+/// If rounding reaches the next larger unit, that unit is used instead, so 1023.99 KiB with a precision of 1 is `1.0 MiB`.
+/// Sizes smaller than one kilobyte are shown as a whole number of bytes without decimal places (e.g. `0 B` or `512 B`).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_bytes_with, ByteUnits};
+///
+/// assert_eq!(humanise_bytes_with(1024, ByteUnits::Binary, 0), "1 KiB");
+/// assert_eq!(humanise_bytes_with(1_234_567, ByteUnits::Decimal, 2), "1.23 MB");
+/// assert_eq!(humanise_bytes_with(1_234_567, ByteUnits::Binary, 3), "1.177 MiB");
+/// ```
+pub fn humanise_bytes_with(bytes: u64, units: ByteUnits, precision: usize) -> String {
+    /// An `f64` holds no more than this many significant decimal digits, so rounding to any more decimal places can't carry into the next unit.
+    const MAX_DECIMALS: usize = f64::DIGITS as usize;
+    let base = units.base();
+    let symbols = units.symbols();
+    if bytes < base {
+        return format!("{} {}", bytes, symbols[0]);
+    }
+
+    let mut index = 0;
+    let mut unit = 1u64;
+    while index < symbols.len() - 1 && bytes / unit >= base {
+        unit *= base;
+        index += 1;
+    }
+    let mut value = bytes as f64 / unit as f64;
+    let scale = 10f64.powi(precision.min(MAX_DECIMALS) as i32);
+    if index < symbols.len() - 1 && (value * scale).round() >= base as f64 * scale {
+        value /= base as f64;
+        index += 1;
+    }
+    format!("{:.*} {}", precision, value, symbols[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(humanise_bytes(1023), "1023 B");
+        assert_eq!(humanise_bytes(1024), "1.0 KiB");
+        assert_eq!(humanise_bytes_decimal(1000), "1.0 KB");
+        assert_eq!(humanise_bytes(1024 * 1024 - 1), "1.0 MiB");
+        assert_eq!(humanise_bytes(1024 * 1024 - 1024), "1023.0 KiB");
+        assert_eq!(humanise_bytes(u64::MAX), "16.0 EiB");
+        assert_eq!(humanise_bytes_decimal(u64::MAX), "18.4 EB");
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(humanise_bytes_with(1536, ByteUnits::Binary, 0), "2 KiB");
+        assert_eq!(humanise_bytes_with(1536, ByteUnits::Binary, 2), "1.50 KiB");
+        assert_eq!(humanise_bytes_with(10, ByteUnits::Decimal, 3), "10 B");
+        let long = humanise_bytes_with(1536, ByteUnits::Binary, 320);
+        assert!(long.starts_with("1.5000") && long.ends_with("0 KiB"));
+        let long = humanise_bytes_with(1024 * 1024 - 1, ByteUnits::Binary, 400);
+        assert!(long.starts_with("1023.9990234375000") && long.ends_with(" KiB"));
+    }
+}
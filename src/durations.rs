@@ -1,12 +1,30 @@
 //! Module for duration humanisation. See [`humanise_duration_ms`] and [`humanise_duration`].
 
-use crate::{humanise_list, plural_suffix};
+use crate::{
+    group_digits, humanise_list, ordinal, plural_suffix, with_article, Gender, Humanise, ListFormat,
+};
+use std::fmt::Display;
+use std::sync::Arc;
 use std::time::Duration;
 
 const SECOND: u128 = 1000;
 const MINUTE: u128 = SECOND * 60;
 const HOUR: u128 = MINUTE * 60;
 const DAY: u128 = HOUR * 24;
+const WEEK: u128 = DAY * 7;
+/// The average length of a month in the Gregorian calendar (30.44 days).
+const AVERAGE_MONTH: u128 = DAY * 3044 / 100;
+/// The average length of a year in the Julian calendar (365.25 days).
+const AVERAGE_YEAR: u128 = DAY * 36525 / 100;
+
+/// The fixed-length units, largest first, with their verbose singular names and their symbols.
+const UNITS: [(u128, &str, &str); 5] = [
+    (DAY, "day", "d"),
+    (HOUR, "hour", "h"),
+    (MINUTE, "minute", "m"),
+    (SECOND, "second", "s"),
+    (1, "millisecond", "ms"),
+];
 
 /// Humanise a duration specified in milliseconds.
 ///
@@ -14,13 +32,14 @@ const DAY: u128 = HOUR * 24;
 ///
 /// * `milliseconds`: The total amount of milliseconds in the duration.
 /// * `verbose`: Whether or not to be verbose or shorten a few of the longer words.
-///              This only changes the words used for minutes (`minutes` becomes `mins`),
-///              seconds (`seconds` becomes `secs`), and milliseconds (`milliseconds` becomes `ms`.
+///   This only changes the words used for minutes (`minutes` becomes `mins`),
+///   seconds (`seconds` becomes `secs`), and milliseconds (`milliseconds` becomes `ms`.
 ///
 /// # Return value
 ///
 /// The duration, humanised.
 /// If `milliseconds` is zero, it defaults to `0 seconds` (or `0 secs` - see `verbose`).
+/// Day counts with more than four digits are grouped with commas (e.g. `12,345 days`).
 ///
 /// # Examples
 ///
@@ -36,65 +55,693 @@ const DAY: u128 = HOUR * 24;
 /// assert_eq!(humanise_duration_ms(62345, true), "1 minute, 2 seconds, and 345 milliseconds");
 /// // Note that humanise_duration_ms only supports units up to days - after that, the units aren't fixed which creates problems.
 /// assert_eq!(humanise_duration_ms(36 * 24 * 60 * 60 * 1000, true), "36 days");
+/// assert_eq!(humanise_duration_ms(12345 * 24 * 60 * 60 * 1000, true), "12,345 days");
 /// ```
 pub fn humanise_duration_ms(milliseconds: u128, verbose: bool) -> String {
+    DurationFormat::preset(verbose).humanise(milliseconds)
+}
+
+/// A duration broken down into its fixed-length units, as returned by [`decompose`].
+///
+/// Each field only holds what is left over after the larger units, so `hours` is always less than 24, `minutes` less than 60, and so on.
+/// `days` has no upper limit, as it is the largest unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DurationParts {
+    /// The number of whole days.
+    pub days: u128,
+    /// The number of whole hours, from 0 to 23.
+    pub hours: u128,
+    /// The number of whole minutes, from 0 to 59.
+    pub minutes: u128,
+    /// The number of whole seconds, from 0 to 59.
+    pub seconds: u128,
+    /// The number of milliseconds, from 0 to 999.
+    pub milliseconds: u128,
+}
+
+impl DurationParts {
+    /// Returns whether every unit is zero, in which case the duration is zero.
+    pub fn is_zero(&self) -> bool {
+        self.counts().iter().all(|count| *count == 0)
+    }
+
+    /// Returns the number of each unit in [`UNITS`] (in the same order).
+    fn counts(&self) -> [u128; UNITS.len()] {
+        [
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+        ]
+    }
+}
+
+/// Splits a duration specified in milliseconds into days, hours, minutes, seconds, and milliseconds.
+///
+/// This is the breakdown that [`humanise_duration_ms`] formats, for when the units are needed as numbers instead.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{decompose, DurationParts};
+///
+/// let parts = decompose(93_784_005);
+/// assert_eq!(
+///     parts,
+///     DurationParts { days: 1, hours: 2, minutes: 3, seconds: 4, milliseconds: 5 }
+/// );
+/// assert!(!parts.is_zero());
+/// assert!(decompose(0).is_zero());
+/// ```
+pub fn decompose(milliseconds: u128) -> DurationParts {
+    let mut remaining_millis = milliseconds;
+    let [days, hours, minutes, seconds, milliseconds] = UNITS.map(|(unit, ..)| {
+        let count = remaining_millis / unit;
+        remaining_millis %= unit;
+        count
+    });
+    DurationParts {
+        days,
+        hours,
+        minutes,
+        seconds,
+        milliseconds,
+    }
+}
+
+/// Humanise a duration specified in milliseconds, including weeks.
+///
+/// This is the same as [`humanise_duration_ms`], except that durations of 7 days or longer are shown in weeks and days.
+/// Since weeks are always exactly 7 days long, the output is still exact.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_weeks;
+///
+/// let day = 24 * 60 * 60 * 1000;
+/// assert_eq!(humanise_duration_weeks(10 * day, true), "1 week and 3 days");
+/// assert_eq!(humanise_duration_weeks(14 * day + 1000, true), "2 weeks and 1 second");
+/// assert_eq!(humanise_duration_weeks(6 * day, true), "6 days");
+/// ```
+pub fn humanise_duration_weeks(milliseconds: u128, verbose: bool) -> String {
+    if milliseconds == 0 {
+        return zero_duration(verbose);
+    }
+    humanise_list(&duration_units_with(
+        milliseconds,
+        verbose,
+        &[(WEEK, "week")],
+    ))
+}
+
+/// Humanise a duration specified in milliseconds, including years, months, and weeks.
+///
+/// **The output of this function is approximate**, as years and months don't have a fixed length.
+/// Years are treated as 365.25 days long and months as 30.44 days long (their average lengths).
+/// For exact output, use [`humanise_duration_ms`] or [`humanise_duration_weeks`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_calendar;
+///
+/// let day = 24 * 60 * 60 * 1000;
+/// assert_eq!(humanise_duration_calendar(day * 36525 / 100, true), "1 year");
+/// assert_eq!(humanise_duration_calendar(day * 3044 / 100 * 2 + 8 * day, true), "2 months, 1 week, and 1 day");
+/// assert_eq!(humanise_duration_calendar(10 * day, true), "1 week and 3 days");
+/// ```
+pub fn humanise_duration_calendar(milliseconds: u128, verbose: bool) -> String {
+    if milliseconds == 0 {
+        return zero_duration(verbose);
+    }
+    humanise_list(&duration_units_with(
+        milliseconds,
+        verbose,
+        &[
+            (AVERAGE_YEAR, "year"),
+            (AVERAGE_MONTH, "month"),
+            (WEEK, "week"),
+        ],
+    ))
+}
+
+/// Humanise a duration specified in milliseconds, only showing the largest units.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+/// * `verbose`: See [`humanise_duration_ms`].
+/// * `max_units`: The maximum number of (non-zero) units to show.
+///
+/// # Return value
+///
+/// The same as [`humanise_duration_ms`], but only with the `max_units` largest non-zero units.
+/// The smaller units are dropped without rounding, so 3 days and 5 hours (and some minutes) with a `max_units` of 2 is `3 days and 5 hours`.
+/// If `max_units` is 0, an empty string is returned. If it is larger than the number of units present, every unit is shown.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_precise;
+///
+/// let duration = (((3 * 24 + 5) * 60 + 12) * 60 + 8) * 1000 + 400;
+/// assert_eq!(humanise_duration_precise(duration, true, 2), "3 days and 5 hours");
+/// assert_eq!(humanise_duration_precise(duration, true, 1), "3 days");
+/// assert_eq!(humanise_duration_precise(duration, false, 10), "3 days, 5 hours, 12 mins, 8 secs, and 400 ms");
+/// ```
+pub fn humanise_duration_precise(milliseconds: u128, verbose: bool, max_units: usize) -> String {
+    if max_units == 0 {
+        return "".to_string();
+    }
+    if milliseconds == 0 {
+        return zero_duration(verbose);
+    }
+    let format = DurationFormat::preset(verbose);
+    let mut units = format.units(decompose(milliseconds));
+    units.truncate(max_units);
+    format.join(&units)
+}
+
+/// Humanise a duration specified in milliseconds using compact unit symbols.
+///
+/// This is intended for places where the prose output of [`humanise_duration_ms`] is too long, such as log lines.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+///
+/// # Return value
+///
+/// Every non-zero unit (largest first) as the count directly followed by the unit's symbol (`d`, `h`, `m`, `s`, or `ms`), separated by spaces.
+/// If `milliseconds` is zero, `0s` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_compact;
+///
+/// assert_eq!(humanise_duration_compact(93_784_500), "1d 2h 3m 4s 500ms");
+/// assert_eq!(humanise_duration_compact(3_600_250), "1h 250ms");
+/// assert_eq!(humanise_duration_compact(0), "0s");
+/// ```
+pub fn humanise_duration_compact(milliseconds: u128) -> String {
     if milliseconds == 0 {
-        return if verbose {
-            "0 seconds".to_string()
+        return "0s".to_string();
+    }
+    decompose(milliseconds)
+        .counts()
+        .into_iter()
+        .zip(UNITS)
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, (_, _, symbol))| format!("{}{}", count, symbol))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Humanise a duration specified in milliseconds, rounded to a single unit.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+///
+/// # Return value
+///
+/// The duration rounded (half-up) to a whole number of the largest unit that fits in it.
+/// If rounding reaches the next larger unit, that unit is used instead, so 59.5 minutes is `1 hour`.
+/// Durations shorter than a second are shown in milliseconds, and zero is `0 seconds`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_rounded;
+///
+/// assert_eq!(humanise_duration_rounded(90 * 60 * 1000), "2 hours");
+/// assert_eq!(humanise_duration_rounded(89 * 1000), "1 minute");
+/// assert_eq!(humanise_duration_rounded(250), "250 milliseconds");
+/// ```
+pub fn humanise_duration_rounded(milliseconds: u128) -> String {
+    let (count, index) = round_to_largest_unit(milliseconds);
+    let (_, name, _) = UNITS[index];
+    let count_str = if index == DurationUnit::Day as usize {
+        format_days(count, ",")
+    } else {
+        count.to_string()
+    };
+    format!("{} {}", count_str, plural_suffix(count, name, false))
+}
+
+/// Humanise a duration specified in milliseconds, joining every unit with `and`.
+///
+/// This produces output that is clearer when read aloud (e.g. by a screen reader) than the comma-separated output of [`humanise_duration_ms`].
+/// Other than how the units are joined, the output is identical to [`humanise_duration_ms`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_ms_spoken;
+///
+/// assert_eq!(humanise_duration_ms_spoken(62345, true), "1 minute and 2 seconds and 345 milliseconds");
+/// assert_eq!(humanise_duration_ms_spoken(1234, true), "1 second and 234 milliseconds");
+/// ```
+pub fn humanise_duration_ms_spoken(milliseconds: u128, verbose: bool) -> String {
+    DurationFormat::preset(verbose)
+        .spoken_joining()
+        .humanise(milliseconds)
+}
+
+/// Converts `duration` to milliseconds, then humanises that for being read aloud.
+///
+/// See [`humanise_duration_ms_spoken`].
+pub fn humanise_duration_spoken(duration: Duration, verbose: bool) -> String {
+    humanise_duration_ms_spoken(duration.as_millis(), verbose)
+}
+
+fn zero_duration(verbose: bool) -> String {
+    DurationFormat::preset(verbose).zero(DurationUnit::Second)
+}
+
+/// One of the fixed-length units used for humanising durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    /// 24 hours.
+    Day,
+    /// 60 minutes.
+    Hour,
+    /// 60 seconds.
+    Minute,
+    /// 1000 milliseconds.
+    Second,
+    /// The smallest unit.
+    Millisecond,
+}
+
+impl DurationUnit {
+    /// The length of this unit in milliseconds.
+    fn milliseconds(self) -> u128 {
+        match self {
+            DurationUnit::Day => DAY,
+            DurationUnit::Hour => HOUR,
+            DurationUnit::Minute => MINUTE,
+            DurationUnit::Second => SECOND,
+            DurationUnit::Millisecond => 1,
+        }
+    }
+}
+
+/// Humanise a duration specified in milliseconds, leaving out any units smaller than `min_unit`.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+/// * `verbose`: See [`humanise_duration_ms`].
+/// * `min_unit`: The smallest unit to show.
+///
+/// # Return value
+///
+/// The same as [`humanise_duration_ms`], but with the remainder smaller than `min_unit` discarded (without rounding).
+/// If nothing is left (because `milliseconds` is less than one `min_unit`), zero of `min_unit` is returned (e.g. `0 minutes`).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_duration_floored, DurationUnit};
+///
+/// assert_eq!(humanise_duration_floored(62_345, true, DurationUnit::Second), "1 minute and 2 seconds");
+/// assert_eq!(humanise_duration_floored(3 * 60 * 60 * 1000 + 47, true, DurationUnit::Minute), "3 hours");
+/// assert_eq!(humanise_duration_floored(59_999, true, DurationUnit::Minute), "0 minutes");
+/// ```
+pub fn humanise_duration_floored(
+    milliseconds: u128,
+    verbose: bool,
+    min_unit: DurationUnit,
+) -> String {
+    let format = DurationFormat::preset(verbose);
+    let floored = milliseconds - milliseconds % min_unit.milliseconds();
+    if floored == 0 {
+        return format.zero(min_unit);
+    }
+    format.humanise(floored)
+}
+
+/// A reusable duration format with custom unit labels, for when the output of [`humanise_duration_ms`] isn't right.
+///
+/// The format is configured once using the builder methods, and [`DurationFormat::humanise`] can then be called as many times as needed.
+/// The verbose and non-verbose output of [`humanise_duration_ms`] are available as the [`DurationFormat::verbose`] and [`DurationFormat::abbreviated`] presets.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{DurationFormat, DurationUnit};
+///
+/// let format = DurationFormat::abbreviated()
+///     .label(DurationUnit::Hour, "hr", "hr")
+///     .label(DurationUnit::Minute, "min", "min")
+///     .label_separator("")
+///     .separator(" ")
+///     .without_conjunction();
+/// assert_eq!(format.humanise(62 * 60 * 1000), "1hr 2min");
+///
+/// let format = DurationFormat::new()
+///     .label(DurationUnit::Hour, "Stunde", "Stunden")
+///     .label(DurationUnit::Minute, "Minute", "Minuten")
+///     .conjunction("und");
+/// assert_eq!(format.humanise(62 * 60 * 1000), "1 Stunde und 2 Minuten");
+///
+/// let format = DurationFormat::new()
+///     .label(DurationUnit::Day, "Tag", "Tage")
+///     .digit_separator(".");
+/// assert_eq!(format.humanise(12_345 * 24 * 60 * 60 * 1000), "12.345 Tage");
+///
+/// let format = DurationFormat::spoken();
+/// assert_eq!(format.humanise(3_723_000), "1 hour and 2 minutes and 3 seconds");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationFormat {
+    labels: [(String, String); UNITS.len()],
+    genders: [Gender; UNITS.len()],
+    count_form: Option<CountForm>,
+    label_separator: String,
+    digit_separator: String,
+    list: ListFormat,
+    conjunction: bool,
+}
+
+impl DurationFormat {
+    /// Creates a new duration format with the same behaviour as [`humanise_duration_ms`] when it is verbose.
+    ///
+    /// This is the same as [`DurationFormat::verbose`].
+    pub fn new() -> Self {
+        Self::verbose()
+    }
+
+    /// Creates a new duration format with the same behaviour as [`humanise_duration_ms`] when it is verbose (e.g. `1 minute and 2 seconds`).
+    pub fn verbose() -> Self {
+        Self {
+            labels: UNITS.map(|(_, name, _)| (name.to_string(), plural_suffix(2, name, false))),
+            genders: [Gender::default(); UNITS.len()],
+            count_form: None,
+            label_separator: " ".to_string(),
+            digit_separator: ",".to_string(),
+            list: ListFormat::new(),
+            conjunction: true,
+        }
+    }
+
+    /// Creates a new duration format with the same behaviour as [`humanise_duration_ms`] when it isn't verbose (e.g. `1 min and 2 secs`).
+    pub fn abbreviated() -> Self {
+        Self::verbose()
+            .label(DurationUnit::Minute, "min", "mins")
+            .label(DurationUnit::Second, "sec", "secs")
+            .label(DurationUnit::Millisecond, "ms", "ms")
+    }
+
+    /// The preset matching the `verbose` parameter of [`humanise_duration_ms`].
+    fn preset(verbose: bool) -> Self {
+        if verbose {
+            Self::verbose()
         } else {
-            "0 secs".to_string()
-        };
+            Self::abbreviated()
+        }
     }
-    let days_mod = milliseconds % DAY;
-    let days = (milliseconds - days_mod) / DAY;
-    let remaining_millis = days_mod;
 
-    let hours_mod = remaining_millis % HOUR;
-    let hours = (remaining_millis - hours_mod) / HOUR;
-    let remaining_millis = hours_mod;
+    /// Creates a new duration format with the same behaviour as [`humanise_duration_ms_spoken`] when it is verbose
+    /// (e.g. `1 hour and 2 minutes and 3 seconds`).
+    ///
+    /// Every unit is joined with `and` rather than commas, which is clearer when read aloud (e.g. by a screen reader).
+    pub fn spoken() -> Self {
+        Self::verbose().spoken_joining()
+    }
 
-    let minutes_mod = remaining_millis % MINUTE;
-    let minutes = (remaining_millis - minutes_mod) / MINUTE;
-    let remaining_millis = minutes_mod;
+    /// Joins every unit with `and`, like [`DurationFormat::spoken`].
+    fn spoken_joining(self) -> Self {
+        self.separator(" and ").without_conjunction()
+    }
 
-    let seconds_mod = remaining_millis % SECOND;
-    let seconds = (remaining_millis - seconds_mod) / SECOND;
+    /// Sets the singular and plural labels of `unit`.
+    pub fn label(
+        mut self,
+        unit: DurationUnit,
+        singular: impl Into<String>,
+        plural: impl Into<String>,
+    ) -> Self {
+        self.labels[unit as usize] = (singular.into(), plural.into());
+        self
+    }
 
-    let milliseconds = seconds_mod;
+    /// Sets the grammatical gender of the label of `unit`, which is passed to [`DurationFormat::count_form`]. Defaults to [`Gender::Neuter`].
+    pub fn gender(mut self, unit: DurationUnit, gender: Gender) -> Self {
+        self.genders[unit as usize] = gender;
+        self
+    }
 
-    let mut vec = vec![];
-    if days > 0 {
-        vec.push(format!("{} {}", days, plural_suffix(days, "day", false)));
+    /// Sets how each count is written, given the count and the gender of its unit's label.
+    ///
+    /// This is for languages where numbers agree with the noun they count, such as `un jour` but `une heure` in French.
+    /// By default, counts are written as digits, with large day counts grouped using [`DurationFormat::digit_separator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use humanise::{DurationFormat, DurationUnit, Gender};
+    ///
+    /// let format = DurationFormat::new()
+    ///     .label(DurationUnit::Day, "jour", "jours")
+    ///     .label(DurationUnit::Hour, "heure", "heures")
+    ///     .gender(DurationUnit::Day, Gender::Masculine)
+    ///     .gender(DurationUnit::Hour, Gender::Feminine)
+    ///     .count_form(|count, gender| match (count, gender) {
+    ///         (1, Gender::Feminine) => "une".to_string(),
+    ///         (1, _) => "un".to_string(),
+    ///         (count, _) => count.to_string(),
+    ///     })
+    ///     .conjunction("et");
+    /// assert_eq!(format.humanise(25 * 60 * 60 * 1000), "un jour et une heure");
+    /// assert_eq!(format.humanise(2 * 60 * 60 * 1000), "2 heures");
+    /// ```
+    pub fn count_form<F>(mut self, count_form: F) -> Self
+    where
+        F: Fn(u128, Gender) -> String + Send + Sync + 'static,
+    {
+        self.count_form = Some(CountForm(Arc::new(count_form)));
+        self
     }
-    if hours > 0 {
-        vec.push(format!("{} {}", hours, plural_suffix(hours, "hour", false)));
+
+    /// Sets what is put between each count and its label. Defaults to a space.
+    pub fn label_separator(mut self, label_separator: impl Into<String>) -> Self {
+        self.label_separator = label_separator.into();
+        self
     }
-    if minutes > 0 {
-        vec.push(format!(
-            "{} {}",
-            minutes,
-            plural_suffix(minutes, if verbose { "minute" } else { "min " }, false)
-        ));
+
+    /// Sets what is put between each group of three digits in large day counts (e.g. `12,345 days`). Defaults to `,`.
+    ///
+    /// An empty separator leaves the digits ungrouped.
+    pub fn digit_separator(mut self, digit_separator: impl Into<String>) -> Self {
+        self.digit_separator = digit_separator.into();
+        self
     }
-    if seconds > 0 {
-        vec.push(format!(
-            "{} {}",
-            seconds,
-            plural_suffix(seconds, if verbose { "second" } else { "sec" }, false)
-        ));
+
+    /// Sets the separator put between units. Defaults to `, `.
+    ///
+    /// See [`ListFormat::separator`].
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.list = self.list.separator(separator);
+        self
     }
-    if milliseconds > 0 {
-        vec.push(format!(
-            "{} {}",
-            milliseconds,
-            if verbose {
-                plural_suffix(milliseconds, "millisecond", false)
+
+    /// Sets the conjunction put before the last unit. Defaults to `and`.
+    ///
+    /// See [`ListFormat::conjunction`].
+    pub fn conjunction(mut self, conjunction: impl Into<String>) -> Self {
+        self.list = self.list.conjunction(conjunction);
+        self.conjunction = true;
+        self
+    }
+
+    /// Removes the conjunction, so that every unit is only joined with the separator.
+    pub fn without_conjunction(mut self) -> Self {
+        self.conjunction = false;
+        self
+    }
+
+    /// Sets the list format used for joining units, replacing the separator and conjunction.
+    ///
+    /// Any [`ListFormat::truncate`] and [`ListFormat::overflow`] options of `list` are ignored, so every non-zero unit is always shown.
+    pub fn list_format(mut self, mut list: ListFormat) -> Self {
+        list.truncate = None;
+        list.overflow = None;
+        self.list = list;
+        self.conjunction = true;
+        self
+    }
+
+    /// Humanise a duration specified in milliseconds using this format.
+    ///
+    /// Every non-zero unit is formatted as its count followed by its singular or plural label, and the units are joined like the list format does.
+    /// If `milliseconds` is zero, `0` followed by the plural label of seconds is returned.
+    pub fn humanise(&self, milliseconds: u128) -> String {
+        let parts = decompose(milliseconds);
+        if parts.is_zero() {
+            return self.zero(DurationUnit::Second);
+        }
+        self.join(&self.units(parts))
+    }
+
+    /// Formats `count` of the unit at `index` in [`UNITS`] with its label.
+    fn format_unit(&self, count: u128, index: usize) -> String {
+        let (singular, plural) = &self.labels[index];
+        format!(
+            "{}{}{}",
+            if let Some(CountForm(count_form)) = &self.count_form {
+                count_form(count, self.genders[index])
+            } else if index == DurationUnit::Day as usize {
+                format_days(count, &self.digit_separator)
             } else {
-                "ms".to_string()
-            }
-        ));
+                count.to_string()
+            },
+            self.label_separator,
+            if count == 1 { singular } else { plural }
+        )
+    }
+
+    /// Formats zero of `unit` with its label (e.g. `0 seconds`).
+    fn zero(&self, unit: DurationUnit) -> String {
+        self.format_unit(0, unit as usize)
+    }
+
+    /// Formats every non-zero unit of `parts` (largest first).
+    fn units(&self, parts: DurationParts) -> Vec<String> {
+        parts
+            .counts()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .map(|(index, count)| self.format_unit(count, index))
+            .collect()
+    }
+
+    /// Joins formatted units with the separator and conjunction.
+    fn join(&self, units: &[String]) -> String {
+        if self.conjunction {
+            self.list.format(units)
+        } else {
+            units.join(&self.list.separator)
+        }
+    }
+}
+
+/// The hook set by [`DurationFormat::count_form`], compared by identity.
+#[derive(Clone)]
+struct CountForm(Arc<dyn Fn(u128, Gender) -> String + Send + Sync>);
+
+impl core::fmt::Debug for CountForm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Fn(u128, Gender) -> String")
+    }
+}
+
+impl PartialEq for CountForm {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CountForm {}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rounds `milliseconds` (half-up) to a whole number of the largest unit that fits, returning the count and the index of the unit in [`UNITS`].
+/// If rounding reaches the next larger unit, that unit is used instead. Zero is returned as 0 seconds.
+fn round_to_largest_unit(milliseconds: u128) -> (u128, usize) {
+    const SECOND_INDEX: usize = 3;
+    let Some(mut index) = UNITS.iter().position(|(unit, ..)| milliseconds >= *unit) else {
+        return (0, SECOND_INDEX);
+    };
+    let round = |unit: u128| milliseconds / unit + (milliseconds % unit * 2 >= unit) as u128;
+    if index > 0 {
+        let (unit, ..) = UNITS[index];
+        let (larger, ..) = UNITS[index - 1];
+        if round(unit) >= larger / unit {
+            index -= 1;
+        }
+    }
+    (round(UNITS[index].0), index)
+}
+
+/// Formats every non-zero unit of `milliseconds` like [`humanise_duration_ms`] does, but splits off the units in `larger_units` (largest first) before the fixed-length units.
+fn duration_units_with(
+    milliseconds: u128,
+    verbose: bool,
+    larger_units: &[(u128, &str)],
+) -> Vec<String> {
+    let mut vec = vec![];
+    let mut remaining_millis = milliseconds;
+    for (unit, name) in larger_units {
+        let count = remaining_millis / unit;
+        remaining_millis %= unit;
+        if count > 0 {
+            vec.push(format!("{} {}", count, plural_suffix(count, name, false)));
+        }
+    }
+    vec.extend(DurationFormat::preset(verbose).units(decompose(remaining_millis)));
+    vec
+}
+
+/// Formats a number of days, grouping the digits with `separator` once there are more than four of them (so `1234` stays as-is, but `12345` is `12,345`).
+/// An empty `separator` leaves the digits ungrouped.
+fn format_days(days: u128, separator: &str) -> String {
+    if days >= 10_000 && !separator.is_empty() {
+        group_digits(days).replace(',', separator)
+    } else {
+        days.to_string()
+    }
+}
+
+/// Humanise a duration specified in milliseconds as the largest unit it contains, along with how far into the next one of that unit it is.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+///
+/// # Return value
+///
+/// The whole number of the largest unit that fits in `milliseconds`, followed by the progress
+/// towards the next one of that unit as a percentage (rounded down) in parentheses.
+/// If the progress is 0%, the parenthesised part is left out.
+/// If `milliseconds` is zero, `0 seconds` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_with_progress;
+///
+/// // 2 hours and 21 minutes.
+/// assert_eq!(humanise_duration_with_progress(8_460_000), "2 hours (35% into the next hour)");
+/// assert_eq!(humanise_duration_with_progress(90_000), "1 minute (50% into the next minute)");
+/// assert_eq!(humanise_duration_with_progress(2000), "2 seconds");
+/// ```
+pub fn humanise_duration_with_progress(milliseconds: u128) -> String {
+    let Some((unit, name, _)) = UNITS.into_iter().find(|(unit, ..)| milliseconds >= *unit) else {
+        return zero_duration(true);
+    };
+    let count = milliseconds / unit;
+    let progress = milliseconds % unit * 100 / unit;
+    let count_str = if unit == DAY {
+        format_days(count, ",")
+    } else {
+        count.to_string()
+    };
+    let counted = format!("{} {}", count_str, plural_suffix(count, name, false));
+    if progress == 0 {
+        counted
+    } else {
+        format!("{} ({}% into the next {})", counted, progress, name)
     }
-    humanise_list(&vec)
 }
 
 /// Converts `duration` to milliseconds, then humanises that.
@@ -104,69 +751,1887 @@ pub fn humanise_duration(duration: Duration, verbose: bool) -> String {
     humanise_duration_ms(duration.as_millis(), verbose)
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
-#[cfg(feature = "chrono")]
-/// Converts `duration` to milliseconds, then humanises that.
+/// Humanises the duration verbosely using [`humanise_duration`].
 ///
-/// See [`humanise_duration_ms`].
-pub fn humanise_duration_chrono(duration: chrono::Duration, verbose: bool) -> String {
-    humanise_duration_ms(duration.num_milliseconds().abs() as u128, verbose)
-}
-/// Converts `duration` to milliseconds, then humanises that.
+/// ```
+/// use humanise::Humanise;
+/// use std::time::Duration;
 ///
-/// See [`humanise_duration_ms`].
-#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
-#[cfg(feature = "time")]
-pub fn humanise_duration_time(duration: time::Duration, verbose: bool) -> String {
-    humanise_duration_ms(duration.whole_milliseconds().abs() as u128, verbose)
+/// assert_eq!(Duration::from_secs(90).humanise(), "1 minute and 30 seconds");
+/// ```
+impl Humanise for Duration {
+    fn humanise(&self) -> String {
+        humanise_duration(*self, true)
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// A duration that is displayed (and serialised) as its humanised form.
+///
+/// Both [`Display`] and [`Serialize`](serde::Serialize) humanise the duration verbosely using [`humanise_duration`],
+/// so this can be used in types that are serialised to produce a humanised string instead of a number.
+/// It can also be deserialised from any string that [`parse_duration`] accepts.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::HumanDuration;
+/// use std::time::Duration;
+///
+/// let duration = HumanDuration(Duration::from_millis(62_000));
+/// assert_eq!(duration.to_string(), "1 minute and 2 seconds");
+/// assert_eq!(serde_json::to_string(&duration).unwrap(), r#""1 minute and 2 seconds""#);
+/// assert_eq!(serde_json::from_str::<HumanDuration>(r#""1m 2s""#).unwrap(), duration);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration(pub Duration);
 
-    const fn duration(millis: u128) -> Duration {
-        Duration::from_millis(millis as u64)
+#[cfg(feature = "serde")]
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&humanise_duration(self.0, true))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HumanDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&humanise_duration(self.0, true))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HumanDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = HumanDuration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a humanised duration")
+            }
+
+            fn visit_str<E>(self, str: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                parse_duration(str).map(HumanDuration).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Converts `duration` to milliseconds, then humanises that.
+///
+/// Any sub-millisecond part of `duration` is truncated.
+/// Negative durations are humanised by their magnitude, so the sign is discarded;
+/// use [`humanise_relative_chrono`] to keep it.
+/// Every [`chrono::Duration`] (including [`chrono::Duration::MIN`]) fits, so this never overflows or panics.
+///
+/// See [`humanise_duration_ms`].
+pub fn humanise_duration_chrono(duration: chrono::Duration, verbose: bool) -> String {
+    humanise_duration_ms(duration.num_milliseconds().unsigned_abs().into(), verbose)
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Humanise a signed [`chrono::Duration`] as a time relative to now.
+///
+/// Negative durations are in the past and positive ones are in the future.
+/// Apart from taking the direction from the sign, this is the same as [`humanise_relative`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_relative_chrono;
+/// use chrono::Duration;
+///
+/// assert_eq!(humanise_relative_chrono(Duration::minutes(-5)), "5 minutes ago");
+/// assert_eq!(humanise_relative_chrono(Duration::hours(3)), "in 3 hours");
+/// assert_eq!(humanise_relative_chrono(Duration::zero()), "just now");
+/// ```
+pub fn humanise_relative_chrono(duration: chrono::Duration) -> String {
+    let milliseconds = duration.num_milliseconds();
+    let direction = if milliseconds < 0 {
+        RelativeDirection::Past
+    } else {
+        RelativeDirection::Future
+    };
+    humanise_relative(
+        Duration::from_millis(milliseconds.unsigned_abs()),
+        direction,
+    )
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// How a time of day is written. Used by [`humanise_time_of_day`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockStyle {
+    /// A 12-hour clock with `AM` or `PM` (`2:00 PM`).
+    TwelveHour,
+    /// A 24-hour clock (`14:00`).
+    #[default]
+    TwentyFourHour,
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Formats a time of day as hours and minutes, leaving out the seconds.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_time_of_day, ClockStyle};
+/// use chrono::NaiveTime;
+///
+/// let time = NaiveTime::from_hms_opt(14, 5, 30).unwrap();
+/// assert_eq!(humanise_time_of_day(time, ClockStyle::TwentyFourHour), "14:05");
+/// assert_eq!(humanise_time_of_day(time, ClockStyle::TwelveHour), "2:05 PM");
+/// ```
+pub fn humanise_time_of_day(time: chrono::NaiveTime, style: ClockStyle) -> String {
+    let format = match style {
+        ClockStyle::TwelveHour => "%-I:%M %p",
+        ClockStyle::TwentyFourHour => "%H:%M",
+    };
+    time.format(format).to_string()
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Humanise when something next happens, using a 24-hour clock.
+///
+/// See [`humanise_next_occurrence_with`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_next_occurrence;
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2024, 1, 1, 14, 0, 0).unwrap();
+/// assert_eq!(humanise_next_occurrence(next, now), "in 2 hours (at 14:00)");
+/// ```
+pub fn humanise_next_occurrence<Tz: chrono::TimeZone>(
+    next: chrono::DateTime<Tz>,
+    now: chrono::DateTime<Tz>,
+) -> String {
+    humanise_next_occurrence_with(next, now, ClockStyle::TwentyFourHour)
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Humanise when something next happens, as both a relative time and a time of day.
+///
+/// # Arguments
+///
+/// * `next`: When it next happens.
+/// * `now`: The current time.
+/// * `style`: How the time of day is written.
+///
+/// # Return value
+///
+/// The time between `now` and `next` as [`humanise_relative_chrono`] would humanise it,
+/// followed by the time of day of `next` (in the time zone of `next`) formatted using [`humanise_time_of_day`] in parentheses.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_next_occurrence_with, ClockStyle};
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2024, 1, 1, 14, 0, 0).unwrap();
+/// assert_eq!(humanise_next_occurrence_with(next, now, ClockStyle::TwelveHour), "in 2 hours (at 2:00 PM)");
+/// ```
+pub fn humanise_next_occurrence_with<Tz: chrono::TimeZone>(
+    next: chrono::DateTime<Tz>,
+    now: chrono::DateTime<Tz>,
+    style: ClockStyle,
+) -> String {
+    let relative = humanise_relative_chrono(next.clone() - now);
+    format!(
+        "{} (at {})",
+        relative,
+        humanise_time_of_day(next.time(), style)
+    )
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[cfg(feature = "chrono")]
+/// Humanise the calendar span between two dates.
+///
+/// Unlike [`humanise_duration_ms`], this uses calendar years and months, so varying month lengths and leap years are accounted for.
+///
+/// # Arguments
+///
+/// * `start`: The first date of the span.
+/// * `end`: The last date of the span. If this is before `start`, the two dates are swapped.
+///
+/// # Return value
+///
+/// The number of whole years, months, and days between `start` and `end`, joined using [`humanise_list`].
+/// Months are added to `start` like [`chrono::NaiveDate::checked_add_months`] does,
+/// so a month after the 31st of January is the last day of February.
+/// If both dates are the same, `0 days` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_date_span;
+/// use chrono::NaiveDate;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// assert_eq!(humanise_date_span(date(2020, 1, 10), date(2022, 4, 15)), "2 years, 3 months, and 5 days");
+/// // February 2024 has 29 days.
+/// assert_eq!(humanise_date_span(date(2024, 2, 1), date(2024, 3, 1)), "1 month");
+/// assert_eq!(humanise_date_span(date(2024, 1, 31), date(2024, 3, 1)), "1 month and 1 day");
+/// ```
+pub fn humanise_date_span(start: chrono::NaiveDate, end: chrono::NaiveDate) -> String {
+    use chrono::{Datelike, Months};
+
+    let (start, end) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    let mut months =
+        ((end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32) as u32;
+    let mut anchor = start + Months::new(months);
+    if anchor > end {
+        months -= 1;
+        anchor = start + Months::new(months);
+    }
+    let years = (months / 12) as u128;
+    let months = (months % 12) as u128;
+    let days = (end - anchor).num_days() as u128;
+
+    let mut vec = vec![];
+    if years > 0 {
+        vec.push(format!("{} {}", years, plural_suffix(years, "year", false)));
+    }
+    if months > 0 {
+        vec.push(format!(
+            "{} {}",
+            months,
+            plural_suffix(months, "month", false)
+        ));
+    }
+    if days > 0 || vec.is_empty() {
+        vec.push(format!("{} {}", days, plural_suffix(days, "day", false)));
+    }
+    humanise_list(&vec)
+}
+
+/// Converts `duration` to milliseconds, then humanises that.
+///
+/// Any sub-millisecond part of `duration` is truncated.
+/// Negative durations are humanised by their magnitude, so the sign is discarded.
+/// Every [`time::Duration`] (including [`time::Duration::MIN`]) fits, so this never overflows or panics.
+///
+/// See [`humanise_duration_ms`].
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+#[cfg(feature = "time")]
+pub fn humanise_duration_time(duration: time::Duration, verbose: bool) -> String {
+    humanise_duration_ms(duration.whole_milliseconds().unsigned_abs(), verbose)
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+#[cfg(feature = "html")]
+/// Humanise `duration` as an HTML `<time>` element.
+///
+/// # Arguments
+///
+/// * `duration`: The duration to humanise.
+/// * `verbose`: See [`humanise_duration_ms`].
+///
+/// # Return value
+///
+/// A `<time>` element with the duration in ISO 8601 format as its `datetime` attribute,
+/// and the duration humanised by [`humanise_duration`] as its content.
+/// Both the attribute and the content are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_html;
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     humanise_duration_html(Duration::from_secs(3720), true),
+///     r#"<time datetime="PT1H2M">1 hour and 2 minutes</time>"#
+/// );
+/// assert_eq!(
+///     humanise_duration_html(Duration::from_millis(90_061_500), true),
+///     r#"<time datetime="P1DT1H1M1.5S">1 day, 1 hour, 1 minute, 1 second, and 500 milliseconds</time>"#
+/// );
+/// ```
+pub fn humanise_duration_html(duration: Duration, verbose: bool) -> String {
+    format!(
+        r#"<time datetime="{}">{}</time>"#,
+        escape_html(&iso8601_duration(duration.as_millis())),
+        escape_html(&humanise_duration(duration, verbose))
+    )
+}
+
+/// Formats `milliseconds` as an ISO 8601 duration (e.g. `P1DT2H3M4.5S`), using days as the largest unit.
+#[cfg(feature = "html")]
+fn iso8601_duration(milliseconds: u128) -> String {
+    let DurationParts {
+        days,
+        hours,
+        minutes,
+        seconds,
+        milliseconds: millis,
+    } = decompose(milliseconds);
+
+    let mut str = "P".to_string();
+    if days > 0 {
+        str += &format!("{}D", days);
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || millis > 0 || days == 0 {
+        str += "T";
+    }
+    if hours > 0 {
+        str += &format!("{}H", hours);
+    }
+    if minutes > 0 {
+        str += &format!("{}M", minutes);
+    }
+    if millis > 0 {
+        let fraction = format!("{:03}", millis);
+        str += &format!("{}.{}S", seconds, fraction.trim_end_matches('0'));
+    } else if seconds > 0 || milliseconds == 0 {
+        str += &format!("{}S", seconds);
+    }
+    str
+}
+
+#[cfg(feature = "html")]
+fn escape_html(str: &str) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    for c in str.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The words used by [`humanise_duration_pct_change_with`] to describe a change in duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeVocabulary {
+    /// Longer durations are `slower`, shorter durations are `faster`.
+    Speed,
+    /// Longer durations are a `regression`, shorter durations are an `improvement`.
+    Quality,
+}
+
+impl ChangeVocabulary {
+    fn word(&self, longer: bool) -> &'static str {
+        match (self, longer) {
+            (ChangeVocabulary::Speed, true) => "slower",
+            (ChangeVocabulary::Speed, false) => "faster",
+            (ChangeVocabulary::Quality, true) => "regression",
+            (ChangeVocabulary::Quality, false) => "improvement",
+        }
+    }
+}
+
+/// Humanise the relative change from `baseline` to `current` as a percentage.
+///
+/// This is the same as calling [`humanise_duration_pct_change_with`] with a precision of 0
+/// and [`ChangeVocabulary::Speed`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_pct_change;
+/// use std::time::Duration;
+///
+/// let baseline = Duration::from_millis(100);
+/// assert_eq!(humanise_duration_pct_change(baseline, Duration::from_millis(112)), "12% slower");
+/// assert_eq!(humanise_duration_pct_change(baseline, Duration::from_millis(92)), "8% faster");
+/// assert_eq!(humanise_duration_pct_change(baseline, baseline), "no change");
+/// ```
+pub fn humanise_duration_pct_change(baseline: Duration, current: Duration) -> String {
+    humanise_duration_pct_change_with(baseline, current, 0, ChangeVocabulary::Speed)
+}
+
+/// Humanise the relative change from `baseline` to `current` as a percentage.
+///
+/// # Arguments
+///
+/// * `baseline`: The duration to compare against.
+/// * `current`: The duration being compared.
+/// * `precision`: The number of decimal places to show in the percentage.
+/// * `vocabulary`: The words to use for describing the change. See [`ChangeVocabulary`].
+///
+/// # Return value
+///
+/// The percentage of the change relative to `baseline`, followed by the word describing it (e.g. `12% slower`).
+/// If the change rounds to zero at the given `precision`, `no change` is returned.
+/// If `baseline` is zero, a percentage cannot be calculated, so only the word is returned
+/// (or `no change` if `current` is zero as well).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_duration_pct_change_with, ChangeVocabulary};
+/// use std::time::Duration;
+///
+/// let baseline = Duration::from_millis(1000);
+/// assert_eq!(
+///     humanise_duration_pct_change_with(baseline, Duration::from_millis(1125), 1, ChangeVocabulary::Quality),
+///     "12.5% regression"
+/// );
+/// assert_eq!(
+///     humanise_duration_pct_change_with(baseline, Duration::from_millis(999), 1, ChangeVocabulary::Speed),
+///     "0.1% faster"
+/// );
+/// // The change is too small to show with no decimal places.
+/// assert_eq!(
+///     humanise_duration_pct_change_with(baseline, Duration::from_millis(999), 0, ChangeVocabulary::Speed),
+///     "no change"
+/// );
+/// ```
+pub fn humanise_duration_pct_change_with(
+    baseline: Duration,
+    current: Duration,
+    precision: usize,
+    vocabulary: ChangeVocabulary,
+) -> String {
+    if baseline == current {
+        return "no change".to_string();
+    }
+    let longer = current > baseline;
+    if baseline.is_zero() {
+        return vocabulary.word(longer).to_string();
+    }
+    let baseline = baseline.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+    let change = ((current - baseline) / baseline * 100.0).abs();
+    let formatted = format!("{:.*}", precision, change);
+    if formatted.trim_matches(|c| c == '0' || c == '.').is_empty() {
+        return "no change".to_string();
+    }
+    format!("{}% {}", formatted, vocabulary.word(longer))
+}
+
+/// A table of relatable reference durations, for use with [`humanise_duration_relatable`].
+pub const RELATABLE_DURATIONS: &[(Duration, &str)] = &[
+    (Duration::from_secs(5), "a deep breath"),
+    (Duration::from_secs(3 * 60), "a pop song"),
+    (Duration::from_secs(15 * 60), "a coffee break"),
+    (Duration::from_secs(30 * 60), "a sitcom episode"),
+    (Duration::from_secs(2 * 60 * 60), "a movie"),
+    (Duration::from_secs(8 * 60 * 60), "a working day"),
+    (Duration::from_secs(7 * 24 * 60 * 60), "a week-long holiday"),
+];
+
+/// Humanise a duration specified in milliseconds by comparing it to the closest relatable reference.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+/// * `references`: The reference durations along with their labels. See [`RELATABLE_DURATIONS`] for a default English table.
+///
+/// # Return value
+///
+/// The reference closest to `milliseconds` is picked, and then:
+/// - if the duration is within 10% of it, `about as long as {label}` is returned,
+/// - if the duration is longer, `longer than {label}` is returned, and
+/// - if the duration is shorter, `shorter than {label}` is returned.
+///
+/// If `references` is empty, an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_duration_relatable, RELATABLE_DURATIONS};
+///
+/// assert_eq!(humanise_duration_relatable(115 * 60 * 1000, RELATABLE_DURATIONS), "about as long as a movie");
+/// assert_eq!(humanise_duration_relatable(20 * 60 * 1000, RELATABLE_DURATIONS), "longer than a coffee break");
+/// assert_eq!(humanise_duration_relatable(1000, RELATABLE_DURATIONS), "shorter than a deep breath");
+/// ```
+pub fn humanise_duration_relatable(milliseconds: u128, references: &[(Duration, &str)]) -> String {
+    let Some((reference, label)) = references
+        .iter()
+        .map(|(duration, label)| (duration.as_millis(), label))
+        .min_by_key(|(reference, _)| reference.abs_diff(milliseconds))
+    else {
+        return "".to_string();
+    };
+    let within_ten_percent = reference
+        .abs_diff(milliseconds)
+        .checked_mul(10)
+        .is_some_and(|difference| difference <= reference);
+    if within_ten_percent {
+        format!("about as long as {}", label)
+    } else if milliseconds > reference {
+        format!("longer than {}", label)
+    } else {
+        format!("shorter than {}", label)
+    }
+}
+
+/// Humanise an uptime along with the availability it represents over a period.
+///
+/// # Arguments
+///
+/// * `uptime`: How long the service has been up.
+/// * `total_period`: The period `uptime` is measured over.
+/// * `precision`: The number of decimal places to show in the availability percentage.
+///
+/// # Return value
+///
+/// `up for {uptime}`, followed by the availability in parentheses.
+/// The percentage is rounded down, so any amount of downtime means that `100%` is never shown
+/// (unless `precision` is so large that the calculation would overflow, in which case it is rounded normally).
+/// If `uptime` covers all of `total_period`, the availability is shown as `100% uptime`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_uptime;
+/// use std::time::Duration;
+///
+/// let day = Duration::from_secs(24 * 60 * 60);
+/// assert_eq!(humanise_uptime(day * 3, day * 3, 1), "up for 3 days (100% uptime)");
+/// assert_eq!(
+///     humanise_uptime(day * 3 - Duration::from_secs(60), day * 3, 2),
+///     "up for 2 days, 23 hours, and 59 minutes (99.97% availability)"
+/// );
+/// // Even a tiny amount of downtime isn't rounded up to 100%.
+/// assert_eq!(humanise_uptime(day - Duration::from_secs(1), day, 1), "up for 23 hours, 59 minutes, and 59 seconds (99.9% availability)");
+/// ```
+pub fn humanise_uptime(uptime: Duration, total_period: Duration, precision: usize) -> String {
+    let uptime_str = humanise_duration(uptime, true);
+    if uptime >= total_period {
+        return format!("up for {} (100% uptime)", uptime_str);
+    }
+    // Integer arithmetic is used where possible so that rounding down is exact.
+    let availability = 10u128
+        .checked_pow(precision as u32)
+        .and_then(|scale| {
+            let scaled = uptime.as_nanos().checked_mul(scale.checked_mul(100)?)?;
+            Some((scale, scaled))
+        })
+        .map(|(scale, scaled)| {
+            let scaled = scaled / total_period.as_nanos();
+            match precision {
+                0 => scaled.to_string(),
+                _ => format!("{}.{:0>precision$}", scaled / scale, scaled % scale),
+            }
+        })
+        .unwrap_or_else(|| {
+            let availability = uptime.as_nanos() as f64 / total_period.as_nanos() as f64 * 100.0;
+            format!("{:.*}", precision, availability)
+        });
+    format!("up for {} ({}% availability)", uptime_str, availability)
+}
+
+/// Humanise a duration specified in milliseconds tersely, as the largest unit it contains with a single decimal.
+///
+/// # Arguments
+///
+/// * `milliseconds`: The total amount of milliseconds in the duration.
+///
+/// # Return value
+///
+/// The duration in the largest unit that fits, rounded (half-up) to one decimal place and followed by the unit's symbol (`d`, `h`, `m`, `s`, or `ms`).
+/// A trailing `.0` is left out, and milliseconds are always shown without decimals.
+/// If rounding makes the value reach the next unit, the next unit is used instead (so 59,970 milliseconds is `1m`, not `60s`).
+/// If `milliseconds` is zero, `0s` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_duration_decimal_compact;
+///
+/// assert_eq!(humanise_duration_decimal_compact(90 * 60 * 1000), "1.5h");
+/// assert_eq!(humanise_duration_decimal_compact(3 * 24 * 60 * 60 * 1000 + 5 * 60 * 60 * 1000), "3.2d");
+/// assert_eq!(humanise_duration_decimal_compact(2000), "2s");
+/// assert_eq!(humanise_duration_decimal_compact(250), "250ms");
+/// ```
+pub fn humanise_duration_decimal_compact(milliseconds: u128) -> String {
+    if milliseconds == 0 {
+        return "0s".to_string();
+    }
+    let mut index = UNITS
+        .iter()
+        .position(|(unit, ..)| milliseconds >= *unit)
+        .unwrap();
+    let tenths =
+        |unit: u128| milliseconds / unit * 10 + (milliseconds % unit * 10 + unit / 2) / unit;
+    if index > 0 {
+        let (unit, ..) = UNITS[index];
+        let (larger, ..) = UNITS[index - 1];
+        if tenths(unit) >= larger / unit * 10 {
+            index -= 1;
+        }
+    }
+    let (unit, _, symbol) = UNITS[index];
+    if unit == 1 {
+        return format!("{}{}", milliseconds, symbol);
+    }
+    let tenths = tenths(unit);
+    match tenths % 10 {
+        0 => format!("{}{}", tenths / 10, symbol),
+        decimal => format!("{}.{}{}", tenths / 10, decimal, symbol),
+    }
+}
+
+/// The default template for [`humanise_timeline`].
+pub const TIMELINE_TEMPLATE: &str = "{label} took {duration}";
+
+/// Humanise a sequence of labelled phases and their durations.
+///
+/// # Arguments
+///
+/// * `phases`: The phases, as pairs of the label and the duration of the phase.
+/// * `template`: The template every phase is formatted with. `{label}` is replaced with the label
+///   and `{duration}` with the duration humanised by [`humanise_duration`]. See [`TIMELINE_TEMPLATE`] for the default.
+/// * `show_total`: Whether to append the total duration of all phases.
+///
+/// # Return value
+///
+/// The formatted phases, joined using [`humanise_list`].
+/// If `show_total` is `true`, the total duration is appended in parentheses.
+/// If there are no phases, an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_timeline, TIMELINE_TEMPLATE};
+/// use std::time::Duration;
+///
+/// let phases = [
+///     ("phase 1", Duration::from_secs(120)),
+///     ("phase 2", Duration::from_secs(300)),
+///     ("phase 3", Duration::from_secs(60)),
+/// ];
+/// assert_eq!(
+///     humanise_timeline(&phases, TIMELINE_TEMPLATE, true),
+///     "phase 1 took 2 minutes, phase 2 took 5 minutes, and phase 3 took 1 minute (8 minutes total)"
+/// );
+/// assert_eq!(
+///     humanise_timeline(&phases[..2], "{label}: {duration}", false),
+///     "phase 1: 2 minutes and phase 2: 5 minutes"
+/// );
+/// ```
+pub fn humanise_timeline<T>(phases: &[(T, Duration)], template: &str, show_total: bool) -> String
+where
+    T: Display,
+{
+    if phases.is_empty() {
+        return "".to_string();
+    }
+    let formatted: Vec<String> = phases
+        .iter()
+        .map(|(label, duration)| {
+            // The duration is substituted first, as it can never contain `{label}`.
+            template
+                .replace("{duration}", &humanise_duration(*duration, true))
+                .replace("{label}", &label.to_string())
+        })
+        .collect();
+    let list = humanise_list(&formatted);
+    if show_total {
+        // Summed as milliseconds, since adding `Duration`s panics on overflow.
+        let total: u128 = phases
+            .iter()
+            .map(|(_, duration)| duration.as_millis())
+            .sum();
+        format!("{} ({} total)", list, humanise_duration_ms(total, true))
+    } else {
+        list
+    }
+}
+
+/// Humanise a retry/backoff schedule.
+///
+/// # Arguments
+///
+/// * `delays`: The delays before each retry, in order.
+/// * `max_steps`: The maximum number of delays to show. If `None`, every delay is shown.
+///   At least one delay is always shown, even if this is `Some(0)`.
+///
+/// # Return value
+///
+/// `retrying in` followed by the delays humanised by [`humanise_duration`] and joined with `, then`.
+/// If some delays are left out because of `max_steps`, `then backing off further` is put at the end.
+/// If there are no delays, an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_backoff;
+/// use std::time::Duration;
+///
+/// let delays = [1, 2, 4, 8].map(Duration::from_secs);
+/// assert_eq!(
+///     humanise_backoff(&delays[..3], None),
+///     "retrying in 1 second, then 2 seconds, then 4 seconds"
+/// );
+/// assert_eq!(
+///     humanise_backoff(&delays, Some(2)),
+///     "retrying in 1 second, then 2 seconds, then backing off further"
+/// );
+/// ```
+pub fn humanise_backoff(delays: &[Duration], max_steps: Option<usize>) -> String {
+    if delays.is_empty() {
+        return "".to_string();
+    }
+    let shown = max_steps.unwrap_or(delays.len()).clamp(1, delays.len());
+    let mut steps: Vec<String> = delays[..shown]
+        .iter()
+        .map(|delay| humanise_duration(*delay, true))
+        .collect();
+    if shown < delays.len() {
+        steps.push("backing off further".to_string());
+    }
+    format!("retrying in {}", steps.join(", then "))
+}
+
+/// Humanise a percentile of a duration specified in milliseconds.
+///
+/// # Arguments
+///
+/// * `percentile`: The percentile (e.g. 95 for the 95th percentile).
+/// * `milliseconds`: The value at that percentile.
+/// * `verbose`: See [`humanise_duration_ms`].
+///
+/// # Return value
+///
+/// The percentile with its ordinal suffix, followed by `percentile:` and the value humanised by [`humanise_duration_ms`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_percentile;
+///
+/// assert_eq!(humanise_percentile(95, 120, false), "95th percentile: 120 ms");
+/// assert_eq!(humanise_percentile(1, 1500, true), "1st percentile: 1 second and 500 milliseconds");
+/// assert_eq!(humanise_percentile(22, 3, true), "22nd percentile: 3 milliseconds");
+/// ```
+pub fn humanise_percentile(percentile: u8, milliseconds: u128, verbose: bool) -> String {
+    format!(
+        "{} percentile: {}",
+        ordinal(percentile.into()),
+        humanise_duration_ms(milliseconds, verbose)
+    )
+}
+
+/// Humanise a position in a queue along with the estimated waiting time.
+///
+/// # Arguments
+///
+/// * `position`: The position in the queue. `0` means that it is currently your turn, `1` that you are next in line.
+/// * `estimated_wait`: The estimated waiting time, which is rounded to a single unit.
+///
+/// # Return value
+///
+/// If `position` is 0, `it's your turn` is returned.
+/// If `position` is 1, `you're next!` is returned.
+/// Otherwise, `you are {position} in line` (with `position` as an ordinal), followed by the estimated waiting time in parentheses.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_queue_position;
+/// use std::time::Duration;
+///
+/// assert_eq!(humanise_queue_position(3, Duration::from_secs(290)), "you are 3rd in line (about 5 minutes)");
+/// assert_eq!(humanise_queue_position(1, Duration::from_secs(30)), "you're next!");
+/// assert_eq!(humanise_queue_position(0, Duration::ZERO), "it's your turn");
+/// ```
+pub fn humanise_queue_position(position: u128, estimated_wait: Duration) -> String {
+    match position {
+        0 => "it's your turn".to_string(),
+        1 => "you're next!".to_string(),
+        _ => {
+            format!(
+                "you are {} in line (about {})",
+                ordinal(position),
+                humanise_duration_rounded(estimated_wait.as_millis())
+            )
+        }
+    }
+}
+
+/// Whether a relative time is in the past or in the future. Used by [`humanise_relative`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeDirection {
+    /// The time is in the past (`5 minutes ago`).
+    Past,
+    /// The time is in the future (`in 5 minutes`).
+    Future,
+}
+
+/// One of the units a relative time can be capped at. Used by [`humanise_relative_capped`].
+///
+/// Months and years are their average lengths (30.44 and 365.25 days), like in [`humanise_duration_calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeUnit {
+    /// 365.25 days.
+    Year,
+    /// 30.44 days.
+    Month,
+    /// 7 days.
+    Week,
+    /// 24 hours.
+    Day,
+    /// 60 minutes.
+    Hour,
+    /// 60 seconds.
+    Minute,
+    /// 1000 milliseconds.
+    Second,
+}
+
+impl RelativeUnit {
+    /// The length of this unit in milliseconds.
+    fn milliseconds(self) -> u128 {
+        match self {
+            RelativeUnit::Year => AVERAGE_YEAR,
+            RelativeUnit::Month => AVERAGE_MONTH,
+            RelativeUnit::Week => WEEK,
+            RelativeUnit::Day => DAY,
+            RelativeUnit::Hour => HOUR,
+            RelativeUnit::Minute => MINUTE,
+            RelativeUnit::Second => SECOND,
+        }
+    }
+
+    /// The singular name of this unit.
+    fn name(self) -> &'static str {
+        match self {
+            RelativeUnit::Year => "year",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Week => "week",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Second => "second",
+        }
+    }
+}
+
+/// Humanise a duration as a time relative to now.
+///
+/// # Arguments
+///
+/// * `duration`: How far from now the time is.
+/// * `direction`: Whether the time is in the past or in the future.
+///
+/// # Return value
+///
+/// If `duration` is less than a second, `just now` is returned.
+/// Otherwise, the duration is rounded (half-up) to a single unit, and returned as `{duration} ago` or `in {duration}` depending on `direction`.
+/// If the rounding changed the duration, it is prefixed with `about`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_relative, RelativeDirection};
+/// use std::time::Duration;
+///
+/// assert_eq!(humanise_relative(Duration::from_secs(300), RelativeDirection::Past), "5 minutes ago");
+/// assert_eq!(humanise_relative(Duration::from_secs(300), RelativeDirection::Future), "in 5 minutes");
+/// assert_eq!(humanise_relative(Duration::from_secs(110 * 60), RelativeDirection::Past), "about 2 hours ago");
+/// assert_eq!(humanise_relative(Duration::from_millis(400), RelativeDirection::Future), "just now");
+/// ```
+pub fn humanise_relative(duration: Duration, direction: RelativeDirection) -> String {
+    humanise_relative_capped(duration, direction, None)
+}
+
+/// Humanise a duration as a time relative to now, capping it at `max_unit`.
+///
+/// # Arguments
+///
+/// * `duration`: How far from now the time is.
+/// * `direction`: Whether the time is in the past or in the future.
+/// * `max_unit`: The largest unit to count in. If `None`, the duration isn't capped.
+///
+/// # Return value
+///
+/// If `duration` is longer than one `max_unit`, `over a {unit} ago` or `in over a {unit}` is returned depending on `direction`
+/// (with `an` instead of `a` where needed, such as `over an hour ago`).
+/// Otherwise, the same as [`humanise_relative`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_relative_capped, RelativeDirection, RelativeUnit};
+/// use std::time::Duration;
+///
+/// let day = 24 * 60 * 60;
+/// let cap = Some(RelativeUnit::Year);
+/// assert_eq!(humanise_relative_capped(Duration::from_secs(437 * day), RelativeDirection::Past, cap), "over a year ago");
+/// assert_eq!(humanise_relative_capped(Duration::from_secs(300 * day), RelativeDirection::Past, cap), "300 days ago");
+/// assert_eq!(humanise_relative_capped(Duration::from_secs(2 * 3600), RelativeDirection::Future, Some(RelativeUnit::Hour)), "in over an hour");
+/// assert_eq!(humanise_relative_capped(Duration::from_secs(437 * day), RelativeDirection::Past, None), "437 days ago");
+/// ```
+pub fn humanise_relative_capped(
+    duration: Duration,
+    direction: RelativeDirection,
+    max_unit: Option<RelativeUnit>,
+) -> String {
+    let milliseconds = duration.as_millis();
+    if milliseconds < SECOND {
+        return "just now".to_string();
+    }
+    if let Some(max_unit) = max_unit.filter(|unit| milliseconds > unit.milliseconds()) {
+        let name = max_unit.name();
+        return match direction {
+            RelativeDirection::Past => format!("over {} ago", with_article(name)),
+            RelativeDirection::Future => format!("in over {}", with_article(name)),
+        };
+    }
+    let (count, index) = round_to_largest_unit(milliseconds);
+    let (unit, ..) = UNITS[index];
+    let amount = format!(
+        "{}{}",
+        if count * unit == milliseconds {
+            ""
+        } else {
+            "about "
+        },
+        humanise_duration_rounded(milliseconds)
+    );
+    match direction {
+        RelativeDirection::Past => format!("{} ago", amount),
+        RelativeDirection::Future => format!("in {}", amount),
+    }
+}
+
+/// An error returned by [`parse_duration`] when the input isn't a valid duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The input was empty (or only contained separators).
+    Empty,
+    /// Some text was found where a number was expected.
+    ExpectedNumber(String),
+    /// A number was malformed (such as `1.` or `1.2.3`).
+    InvalidNumber(String),
+    /// A number wasn't followed by a unit.
+    MissingUnit(String),
+    /// A number was followed by a unit that isn't recognised.
+    UnknownUnit(String),
+    /// The duration is too long to be represented by a [`Duration`].
+    Overflow,
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "empty duration"),
+            ParseDurationError::ExpectedNumber(text) => {
+                write!(f, "expected a number, found `{}`", text)
+            }
+            ParseDurationError::InvalidNumber(number) => write!(f, "invalid number `{}`", number),
+            ParseDurationError::MissingUnit(number) => {
+                write!(f, "missing a unit after `{}`", number)
+            }
+            ParseDurationError::UnknownUnit(unit) => write!(f, "unknown unit `{}`", unit),
+            ParseDurationError::Overflow => write!(f, "duration is too long"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parse a humanised duration back into a [`Duration`].
+///
+/// # Arguments
+///
+/// * `str`: The duration to parse.
+///
+/// # Return value
+///
+/// The sum of every number and unit pair in `str`.
+/// The pairs may be separated by whitespace, commas, and the word `and`, or not separated at all,
+/// so both the output of [`humanise_duration_ms`] and that of [`humanise_duration_compact`] are accepted.
+/// Numbers may have a fractional part (e.g. `1.5h`), which is truncated to whole milliseconds,
+/// and may have their digits grouped in threes with commas (e.g. `12,345 days`).
+///
+/// The recognised units (case-insensitively) are:
+/// - `w`, `week`, `weeks`
+/// - `d`, `day`, `days`
+/// - `h`, `hr`, `hrs`, `hour`, `hours`
+/// - `m`, `min`, `mins`, `minute`, `minutes`
+/// - `s`, `sec`, `secs`, `second`, `seconds`
+/// - `ms`, `millisecond`, `milliseconds`
+///
+/// If `str` isn't a valid duration, a [`ParseDurationError`] describing the problem is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{parse_duration, ParseDurationError};
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1h 30m"), Ok(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration("2 days, 5 minutes"), Ok(Duration::from_secs(2 * 86400 + 5 * 60)));
+/// assert_eq!(parse_duration("1d2h3m"), Ok(Duration::from_secs(86400 + 2 * 3600 + 3 * 60)));
+/// assert_eq!(parse_duration("1 min, 2 secs, and 345 ms"), Ok(Duration::from_millis(62345)));
+/// assert_eq!(parse_duration("1.5h"), Ok(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration("3 fortnights"), Err(ParseDurationError::UnknownUnit("fortnights".to_string())));
+/// ```
+pub fn parse_duration(str: &str) -> Result<Duration, ParseDurationError> {
+    let mut rest = str;
+    let mut total: u128 = 0;
+    let mut empty = true;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+        let mut number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        // Allow digit grouping (such as in `12,345 days`), but only with groups of exactly three digits.
+        while let Some(group) = rest[number_len..].strip_prefix(',') {
+            let group_len = group
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(group.len());
+            if number_len == 0 || group_len != 3 {
+                break;
+            }
+            number_len += 4;
+        }
+        if number_len == 0 {
+            let word_len = rest
+                .find(|c: char| c.is_whitespace() || c == ',')
+                .unwrap_or(rest.len());
+            let word = &rest[..word_len];
+            if word.eq_ignore_ascii_case("and") {
+                rest = &rest[word_len..];
+                continue;
+            }
+            return Err(ParseDurationError::ExpectedNumber(word.to_string()));
+        }
+        let number = &rest[..number_len];
+        rest = rest[number_len..].trim_start();
+
+        let unit_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        if unit_len == 0 {
+            return Err(ParseDurationError::MissingUnit(number.to_string()));
+        }
+        let unit_name = &rest[..unit_len];
+        rest = &rest[unit_len..];
+        let unit = match unit_name.to_lowercase().as_str() {
+            "w" | "week" | "weeks" => WEEK,
+            "d" | "day" | "days" => DAY,
+            "h" | "hr" | "hrs" | "hour" | "hours" => HOUR,
+            "m" | "min" | "mins" | "minute" | "minutes" => MINUTE,
+            "s" | "sec" | "secs" | "second" | "seconds" => SECOND,
+            "ms" | "millisecond" | "milliseconds" => 1,
+            _ => return Err(ParseDurationError::UnknownUnit(unit_name.to_string())),
+        };
+
+        total = total
+            .checked_add(number_milliseconds(number, unit)?)
+            .ok_or(ParseDurationError::Overflow)?;
+        empty = false;
+    }
+    if empty {
+        return Err(ParseDurationError::Empty);
+    }
+    let seconds = u64::try_from(total / SECOND).map_err(|_| ParseDurationError::Overflow)?;
+    Ok(Duration::from_secs(seconds) + Duration::from_millis((total % SECOND) as u64))
+}
+
+/// Multiplies the decimal `number` by `unit` for [`parse_duration`], truncating the result to a whole number of milliseconds.
+fn number_milliseconds(number: &str, unit: u128) -> Result<u128, ParseDurationError> {
+    let invalid = || ParseDurationError::InvalidNumber(number.to_string());
+    let ungrouped = number.replace(',', "");
+    if matches!((number.find(','), number.find('.')), (Some(comma), Some(dot)) if comma > dot) {
+        return Err(invalid());
+    }
+    let number = ungrouped.as_str();
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    if whole.is_empty() || (number.contains('.') && fraction.is_empty()) || fraction.contains('.') {
+        return Err(invalid());
+    }
+    let whole = whole
+        .parse::<u128>()
+        .map_err(|_| ParseDurationError::Overflow)?
+        .checked_mul(unit)
+        .ok_or(ParseDurationError::Overflow)?;
+    // Digits beyond the 15th can't affect the result, since no unit is longer than 10^15 milliseconds.
+    let fraction = &fraction[..fraction.len().min(15)];
+    let fraction = match fraction {
+        "" => 0,
+        fraction => {
+            fraction.parse::<u128>().map_err(|_| invalid())? * unit
+                / 10u128.pow(fraction.len() as u32)
+        }
+    };
+    whole
+        .checked_add(fraction)
+        .ok_or(ParseDurationError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn duration(millis: u128) -> Duration {
+        Duration::from_millis(millis as u64)
+    }
+
+    #[test]
+    fn single_units_verbose() {
+        macro_rules! test {
+            ($duration:expr => $noun:literal, $max_safe:literal) => {
+                assert_eq!(
+                    humanise_duration(duration($duration * 1), true),
+                    concat!("1 ", $noun)
+                );
+                assert_eq!(
+                    humanise_duration(duration($duration * 2), true),
+                    concat!("2 ", $noun, "s")
+                );
+                assert_eq!(
+                    humanise_duration(duration($duration * $max_safe), true),
+                    concat!($max_safe, " ", $noun, "s")
+                );
+            };
+        }
+        test!(DAY => "day", 128);
+        test!(HOUR => "hour", 23);
+        test!(MINUTE => "minute", 59);
+        test!(SECOND => "second", 59);
+        test!(1 => "millisecond", 999);
+    }
+
+    #[test]
+    fn multiple_units_verbose() {
+        assert_eq!(
+            humanise_duration(duration(DAY + HOUR), true),
+            "1 day and 1 hour"
+        );
+        assert_eq!(
+            humanise_duration(duration(DAY + MINUTE), true),
+            "1 day and 1 minute"
+        );
+        assert_eq!(
+            humanise_duration(duration(3 * DAY + 7 * HOUR), true),
+            "3 days and 7 hours"
+        );
+    }
+
+    #[test]
+    fn pct_change_zero_baseline() {
+        assert_eq!(
+            humanise_duration_pct_change(duration(0), duration(0)),
+            "no change"
+        );
+        assert_eq!(
+            humanise_duration_pct_change(duration(0), duration(5)),
+            "slower"
+        );
+        assert_eq!(
+            humanise_duration_pct_change_with(
+                duration(0),
+                duration(5),
+                2,
+                ChangeVocabulary::Quality
+            ),
+            "regression"
+        );
+    }
+
+    #[test]
+    fn pct_change_rounding() {
+        assert_eq!(
+            humanise_duration_pct_change(duration(1000), duration(2000)),
+            "100% slower"
+        );
+        assert_eq!(
+            humanise_duration_pct_change(duration(1000), duration(0)),
+            "100% faster"
+        );
+        assert_eq!(
+            humanise_duration_pct_change_with(
+                duration(3000),
+                duration(2000),
+                2,
+                ChangeVocabulary::Quality
+            ),
+            "33.33% improvement"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_span() {
+        use chrono::NaiveDate;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            humanise_date_span(date(2024, 5, 5), date(2024, 5, 5)),
+            "0 days"
+        );
+        assert_eq!(
+            humanise_date_span(date(2023, 3, 1), date(2022, 12, 31)),
+            "2 months and 1 day"
+        );
+        // Leap day to the next (non-leap) year.
+        assert_eq!(
+            humanise_date_span(date(2024, 2, 29), date(2025, 2, 28)),
+            "1 year"
+        );
+        assert_eq!(
+            humanise_date_span(date(2023, 2, 28), date(2024, 2, 29)),
+            "1 year and 1 day"
+        );
+        assert_eq!(
+            humanise_date_span(date(2021, 12, 15), date(2022, 1, 14)),
+            "30 days"
+        );
+    }
+
+    #[test]
+    fn spoken() {
+        assert_eq!(humanise_duration_spoken(duration(0), true), "0 seconds");
+        assert_eq!(
+            humanise_duration_spoken(duration(HOUR + 2 * MINUTE + 3 * SECOND), true),
+            "1 hour and 2 minutes and 3 seconds"
+        );
+        assert_eq!(
+            humanise_duration_spoken(duration(DAY + 5), false),
+            "1 day and 5 ms"
+        );
     }
 
     #[test]
-    fn single_units_verbose() {
-        macro_rules! test {
-            ($duration:expr => $noun:literal, $max_safe:literal) => {
-                assert_eq!(
-                    humanise_duration(duration($duration * 1), true),
-                    concat!("1 ", $noun)
-                );
-                assert_eq!(
-                    humanise_duration(duration($duration * 2), true),
-                    concat!("2 ", $noun, "s")
-                );
-                assert_eq!(
-                    humanise_duration(duration($duration * $max_safe), true),
-                    concat!($max_safe, " ", $noun, "s")
-                );
-            };
+    fn with_progress() {
+        assert_eq!(humanise_duration_with_progress(0), "0 seconds");
+        assert_eq!(humanise_duration_with_progress(999), "999 milliseconds");
+        assert_eq!(
+            humanise_duration_with_progress(DAY + DAY / 4),
+            "1 day (25% into the next day)"
+        );
+        assert_eq!(
+            humanise_duration_with_progress(2 * MINUTE - 1),
+            "1 minute (99% into the next minute)"
+        );
+    }
+
+    #[cfg(feature = "html")]
+    #[test]
+    fn iso8601() {
+        assert_eq!(iso8601_duration(0), "PT0S");
+        assert_eq!(iso8601_duration(DAY), "P1D");
+        assert_eq!(iso8601_duration(2 * DAY + 30 * SECOND), "P2DT30S");
+        assert_eq!(iso8601_duration(250), "PT0.25S");
+        assert_eq!(
+            escape_html(r#"<a href="x">&'"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&#39;"
+        );
+    }
+
+    #[test]
+    fn relatable() {
+        assert_eq!(humanise_duration_relatable(MINUTE, &[]), "");
+        let references = [(duration(10 * SECOND), "ten seconds")];
+        assert_eq!(
+            humanise_duration_relatable(9 * SECOND, &references),
+            "about as long as ten seconds"
+        );
+        assert_eq!(
+            humanise_duration_relatable(11 * SECOND, &references),
+            "about as long as ten seconds"
+        );
+        assert_eq!(
+            humanise_duration_relatable(11 * SECOND + 1, &references),
+            "longer than ten seconds"
+        );
+        assert_eq!(
+            humanise_duration_relatable(0, &references),
+            "shorter than ten seconds"
+        );
+        assert_eq!(
+            humanise_duration_relatable(30 * DAY, RELATABLE_DURATIONS),
+            "longer than a week-long holiday"
+        );
+        assert_eq!(
+            humanise_duration_relatable(u128::MAX, RELATABLE_DURATIONS),
+            "longer than a week-long holiday"
+        );
+        let references = [(Duration::MAX, "forever")];
+        assert_eq!(
+            humanise_duration_relatable(0, &references),
+            "shorter than forever"
+        );
+        assert_eq!(
+            humanise_duration_relatable(u128::MAX, &references),
+            "longer than forever"
+        );
+    }
+
+    #[test]
+    fn uptime() {
+        assert_eq!(
+            humanise_uptime(duration(0), duration(0), 2),
+            "up for 0 seconds (100% uptime)"
+        );
+        assert_eq!(
+            humanise_uptime(duration(0), duration(DAY), 0),
+            "up for 0 seconds (0% availability)"
+        );
+        assert_eq!(
+            humanise_uptime(duration(DAY / 2), duration(DAY), 1),
+            "up for 12 hours (50.0% availability)"
+        );
+        assert_eq!(
+            humanise_uptime(duration(999_999), duration(1_000_000), 3),
+            "up for 16 minutes, 39 seconds, and 999 milliseconds (99.999% availability)"
+        );
+        assert_eq!(
+            humanise_uptime(duration(999_999), duration(1_000_000), 2),
+            "up for 16 minutes, 39 seconds, and 999 milliseconds (99.99% availability)"
+        );
+        assert_eq!(
+            humanise_uptime(duration(29), duration(100), 2),
+            "up for 29 milliseconds (29.00% availability)"
+        );
+        // Too precise for integer arithmetic, but it still shouldn't panic.
+        assert!(humanise_uptime(duration(29), duration(100), 40).ends_with("% availability)"));
+    }
+
+    #[test]
+    fn decimal_compact() {
+        assert_eq!(humanise_duration_decimal_compact(0), "0s");
+        assert_eq!(humanise_duration_decimal_compact(999), "999ms");
+        assert_eq!(humanise_duration_decimal_compact(1049), "1s");
+        assert_eq!(humanise_duration_decimal_compact(1050), "1.1s");
+        assert_eq!(humanise_duration_decimal_compact(59_949), "59.9s");
+        assert_eq!(humanise_duration_decimal_compact(59_950), "1m");
+        assert_eq!(humanise_duration_decimal_compact(DAY * 400), "400d");
+        assert!(humanise_duration_decimal_compact(u128::MAX).ends_with('d'));
+    }
+
+    #[test]
+    fn timeline() {
+        assert_eq!(humanise_timeline::<&str>(&[], TIMELINE_TEMPLATE, true), "");
+        assert_eq!(
+            humanise_timeline(&[("build", duration(HOUR))], TIMELINE_TEMPLATE, true),
+            "build took 1 hour (1 hour total)"
+        );
+        assert_eq!(
+            humanise_timeline(
+                &[
+                    ("a".to_string(), duration(SECOND)),
+                    ("b".to_string(), duration(500))
+                ],
+                "{duration} for {label}",
+                true
+            ),
+            "1 second for a and 500 milliseconds for b (1 second and 500 milliseconds total)"
+        );
+        let total = humanise_timeline(
+            &[("a", Duration::MAX), ("b", duration(SECOND))],
+            TIMELINE_TEMPLATE,
+            true,
+        );
+        assert!(total.ends_with(&format!(
+            "({} total)",
+            humanise_duration_ms(Duration::MAX.as_millis() + SECOND, true)
+        )));
+    }
+
+    #[test]
+    fn backoff() {
+        assert_eq!(humanise_backoff(&[], Some(2)), "");
+        assert_eq!(
+            humanise_backoff(&[duration(500)], None),
+            "retrying in 500 milliseconds"
+        );
+        assert_eq!(
+            humanise_backoff(&[duration(SECOND), duration(MINUTE)], Some(2)),
+            "retrying in 1 second, then 1 minute"
+        );
+        assert_eq!(
+            humanise_backoff(&[duration(SECOND), duration(MINUTE)], Some(0)),
+            "retrying in 1 second, then backing off further"
+        );
+    }
+
+    #[test]
+    fn percentile() {
+        assert_eq!(
+            humanise_percentile(3, SECOND, true),
+            "3rd percentile: 1 second"
+        );
+        assert_eq!(
+            humanise_percentile(11, 0, true),
+            "11th percentile: 0 seconds"
+        );
+        assert_eq!(humanise_percentile(99, 20, false), "99th percentile: 20 ms");
+    }
+
+    #[test]
+    fn rounding_to_largest_unit() {
+        assert_eq!(round_to_largest_unit(0), (0, 3));
+        assert_eq!(round_to_largest_unit(499), (499, 4));
+        assert_eq!(round_to_largest_unit(1499), (1, 3));
+        assert_eq!(round_to_largest_unit(1500), (2, 3));
+        assert_eq!(round_to_largest_unit(59_499), (59, 3));
+        assert_eq!(round_to_largest_unit(59_500), (1, 2));
+        assert_eq!(round_to_largest_unit(90 * MINUTE), (2, 1));
+        assert_eq!(round_to_largest_unit(23 * HOUR + 30 * MINUTE), (1, 0));
+    }
+
+    #[test]
+    fn queue_position() {
+        assert_eq!(
+            humanise_queue_position(2, duration(HOUR + 10 * MINUTE)),
+            "you are 2nd in line (about 1 hour)"
+        );
+        assert_eq!(
+            humanise_queue_position(11, duration(MINUTE)),
+            "you are 11th in line (about 1 minute)"
+        );
+        assert_eq!(
+            humanise_queue_position(21, duration(0)),
+            "you are 21st in line (about 0 seconds)"
+        );
+    }
+
+    #[test]
+    fn minutes_not_verbose() {
+        assert_eq!(humanise_duration(duration(MINUTE), false), "1 min");
+        assert_eq!(humanise_duration(duration(2 * MINUTE), false), "2 mins");
+        assert_eq!(
+            humanise_duration(duration(2 * MINUTE + SECOND), false),
+            "2 mins and 1 sec"
+        );
+    }
+
+    #[test]
+    fn precise() {
+        assert_eq!(humanise_duration_precise(0, true, 0), "");
+        assert_eq!(humanise_duration_precise(0, true, 1), "0 seconds");
+        assert_eq!(humanise_duration_precise(DAY + 1, true, 0), "");
+        // Zero units in between are skipped rather than counted.
+        assert_eq!(
+            humanise_duration_precise(DAY + SECOND + 1, true, 2),
+            "1 day and 1 second"
+        );
+        assert_eq!(
+            humanise_duration_precise(HOUR + MINUTE + SECOND, true, 3),
+            humanise_duration_ms(HOUR + MINUTE + SECOND, true)
+        );
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(humanise_duration_weeks(0, false), "0 secs");
+        assert_eq!(humanise_duration_weeks(WEEK, true), "1 week");
+        assert_eq!(
+            humanise_duration_weeks(53 * WEEK + DAY + MINUTE, false),
+            "53 weeks, 1 day, and 1 min"
+        );
+    }
+
+    #[test]
+    fn calendar() {
+        assert_eq!(humanise_duration_calendar(0, true), "0 seconds");
+        assert_eq!(humanise_duration_calendar(AVERAGE_MONTH, true), "1 month");
+        assert_eq!(
+            humanise_duration_calendar(2 * AVERAGE_YEAR + WEEK, true),
+            "2 years and 1 week"
+        );
+        // 365 days is just short of an average year.
+        assert_eq!(
+            humanise_duration_calendar(365 * DAY, true),
+            "11 months, 4 weeks, 2 days, 3 hours, 50 minutes, and 24 seconds"
+        );
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(humanise_duration_compact(1), "1ms");
+        assert_eq!(humanise_duration_compact(2 * DAY + 5 * SECOND), "2d 5s");
+        assert_eq!(humanise_duration_compact(59 * MINUTE), "59m");
+    }
+
+    #[test]
+    fn relative() {
+        use RelativeDirection::*;
+        assert_eq!(humanise_relative(Duration::ZERO, Past), "just now");
+        assert_eq!(humanise_relative(duration(SECOND), Past), "1 second ago");
+        assert_eq!(humanise_relative(duration(DAY), Future), "in 1 day");
+        assert_eq!(
+            humanise_relative(duration(23 * HOUR + 40 * MINUTE), Future),
+            "in about 1 day"
+        );
+        assert_eq!(
+            humanise_relative(duration(89 * SECOND), Past),
+            "about 1 minute ago"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn next_occurrence() {
+        use chrono::{NaiveTime, TimeZone, Utc};
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(
+            humanise_time_of_day(midnight, ClockStyle::TwelveHour),
+            "12:00 AM"
+        );
+        assert_eq!(
+            humanise_time_of_day(midnight, ClockStyle::default()),
+            "00:00"
+        );
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 23, 30, 0).unwrap();
+        assert_eq!(
+            humanise_next_occurrence(now + chrono::Duration::minutes(45), now),
+            "in 45 minutes (at 00:15)"
+        );
+        assert_eq!(
+            humanise_next_occurrence_with(
+                now - chrono::Duration::minutes(5),
+                now,
+                ClockStyle::TwelveHour
+            ),
+            "5 minutes ago (at 11:25 PM)"
+        );
+        assert_eq!(humanise_next_occurrence(now, now), "just now (at 23:30)");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_extremes() {
+        assert_eq!(
+            humanise_duration_chrono(chrono::Duration::minutes(-5), true),
+            "5 minutes"
+        );
+        assert!(humanise_duration_chrono(chrono::Duration::MIN, false).ends_with("807 ms"));
+        assert_eq!(
+            humanise_relative_chrono(chrono::Duration::MIN),
+            "about 106,751,991,167 days ago"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_extremes() {
+        assert_eq!(
+            humanise_duration_time(time::Duration::seconds(-90), true),
+            "1 minute and 30 seconds"
+        );
+        assert!(humanise_duration_time(time::Duration::MIN, true)
+            .starts_with("106,751,991,167,300 days"));
+    }
+
+    #[test]
+    fn rounded() {
+        assert_eq!(humanise_duration_rounded(0), "0 seconds");
+        assert_eq!(humanise_duration_rounded(1), "1 millisecond");
+        assert_eq!(humanise_duration_rounded(999), "999 milliseconds");
+        assert_eq!(humanise_duration_rounded(59 * SECOND + 500), "1 minute");
+        assert_eq!(humanise_duration_rounded(23 * HOUR + 30 * MINUTE), "1 day");
+        assert_eq!(
+            humanise_duration_rounded(23 * HOUR + 30 * MINUTE - 1),
+            "23 hours"
+        );
+        assert_eq!(humanise_duration_rounded(36 * HOUR), "2 days");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_duration("0s"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("1W 1D"), Ok(duration(WEEK + DAY)));
+        assert_eq!(parse_duration(" 250ms,"), Ok(duration(250)));
+        assert_eq!(parse_duration("0.0015 s"), Ok(duration(1)));
+        for ms in [
+            1,
+            59 * SECOND,
+            3 * DAY + 5 * HOUR + MINUTE,
+            DAY + HOUR + MINUTE + SECOND + 1,
+        ] {
+            assert_eq!(
+                parse_duration(&humanise_duration_ms(ms, true)),
+                Ok(duration(ms))
+            );
+            assert_eq!(
+                parse_duration(&humanise_duration_ms(ms, false)),
+                Ok(duration(ms))
+            );
+            assert_eq!(
+                parse_duration(&humanise_duration_compact(ms)),
+                Ok(duration(ms))
+            );
         }
-        test!(DAY => "day", 128);
-        test!(HOUR => "hour", 23);
-        test!(MINUTE => "minute", 59);
-        test!(SECOND => "second", 59);
-        test!(1 => "millisecond", 999);
     }
 
     #[test]
-    fn multiple_units_verbose() {
+    fn parse_errors() {
+        use ParseDurationError::*;
+        assert_eq!(parse_duration(""), Err(Empty));
+        assert_eq!(parse_duration(" , and "), Err(Empty));
+        assert_eq!(parse_duration("5"), Err(MissingUnit("5".to_string())));
+        assert_eq!(parse_duration("5 ,m"), Err(MissingUnit("5".to_string())));
+        assert_eq!(parse_duration("h5"), Err(ExpectedNumber("h5".to_string())));
         assert_eq!(
-            humanise_duration(duration(DAY + HOUR), true),
-            "1 day and 1 hour"
+            parse_duration("1.2.3s"),
+            Err(InvalidNumber("1.2.3".to_string()))
         );
+        assert_eq!(parse_duration(".5s"), Err(InvalidNumber(".5".to_string())));
+        assert_eq!(parse_duration("5.s"), Err(InvalidNumber("5.".to_string())));
         assert_eq!(
-            humanise_duration(duration(DAY + MINUTE), true),
-            "1 day and 1 minute"
+            parse_duration("5 parsecs"),
+            Err(UnknownUnit("parsecs".to_string()))
         );
         assert_eq!(
-            humanise_duration(duration(3 * DAY + 7 * HOUR), true),
-            "3 days and 7 hours"
+            parse_duration("99999999999999999999999 days"),
+            Err(Overflow)
+        );
+        assert_eq!(parse_duration(&format!("{}ms", u128::MAX)), Err(Overflow));
+        assert_eq!(
+            UnknownUnit("parsecs".to_string()).to_string(),
+            "unknown unit `parsecs`"
+        );
+    }
+
+    #[test]
+    fn floored() {
+        use DurationUnit::*;
+        assert_eq!(humanise_duration_floored(0, false, Millisecond), "0 ms");
+        assert_eq!(humanise_duration_floored(0, true, Second), "0 seconds");
+        assert_eq!(humanise_duration_floored(DAY - 1, false, Day), "0 days");
+        assert_eq!(
+            humanise_duration_floored(DAY + HOUR + 1, false, Millisecond),
+            "1 day, 1 hour, and 1 ms"
+        );
+        assert_eq!(
+            humanise_duration_floored(2 * DAY + 23 * HOUR, true, Day),
+            "2 days"
+        );
+        assert_eq!(
+            humanise_duration_floored(HOUR + 59 * MINUTE, false, Hour),
+            "1 hour"
+        );
+    }
+
+    #[test]
+    fn duration_format_presets() {
+        for ms in [
+            0,
+            1,
+            SECOND,
+            MINUTE + 2 * SECOND,
+            DAY + HOUR + MINUTE + SECOND + 1,
+            2 * DAY + 500,
+        ] {
+            assert_eq!(
+                DurationFormat::verbose().humanise(ms),
+                humanise_duration_ms(ms, true)
+            );
+            assert_eq!(
+                DurationFormat::abbreviated().humanise(ms),
+                humanise_duration_ms(ms, false)
+            );
+            assert_eq!(
+                DurationFormat::spoken().humanise(ms),
+                humanise_duration_ms_spoken(ms, true)
+            );
+        }
+        assert_eq!(DurationFormat::default(), DurationFormat::new());
+    }
+
+    #[test]
+    fn duration_format_custom() {
+        let format = DurationFormat::new()
+            .label(DurationUnit::Day, "jour", "jours")
+            .list_format(ListFormat::new().conjunction("et").oxford_comma(false));
+        assert_eq!(
+            format.humanise(2 * DAY + HOUR + SECOND),
+            "2 jours, 1 hour et 1 second"
+        );
+        assert_eq!(
+            DurationFormat::new()
+                .list_format(
+                    ListFormat::new()
+                        .truncate(1)
+                        .overflow(|_| "more".to_string())
+                )
+                .humanise(90_061_000),
+            "1 day, 1 hour, 1 minute, and 1 second"
+        );
+        let spanish = DurationFormat::new()
+            .label(DurationUnit::Hour, "hora", "horas")
+            .gender(DurationUnit::Hour, Gender::Feminine)
+            .count_form(|count, gender| match (count, gender) {
+                (1, Gender::Feminine) => "una".to_string(),
+                (1, _) => "un".to_string(),
+                (count, _) => count.to_string(),
+            });
+        assert_eq!(spanish.humanise(HOUR), "una hora");
+        assert_eq!(spanish.humanise(0), "0 seconds");
+        assert_eq!(spanish.clone(), spanish);
+        assert_ne!(spanish, DurationFormat::new());
+        assert_eq!(
+            DurationFormat::new()
+                .gender(DurationUnit::Day, Gender::Masculine)
+                .humanise(DAY),
+            "1 day"
+        );
+        assert_eq!(
+            DurationFormat::new().label_separator("").humanise(0),
+            "0seconds"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn human_duration_serde() {
+        assert_eq!(
+            serde_json::to_string(&HumanDuration(Duration::ZERO)).unwrap(),
+            r#""0 seconds""#
+        );
+        let error = serde_json::from_str::<HumanDuration>(r#""5 parsecs""#).unwrap_err();
+        assert!(error.to_string().contains("unknown unit `parsecs`"));
+        assert!(serde_json::from_str::<HumanDuration>("5").is_err());
+    }
+
+    #[test]
+    fn grouped_days() {
+        assert_eq!(humanise_duration_ms(9999 * DAY, true), "9999 days");
+        assert_eq!(humanise_duration_ms(10_000 * DAY, true), "10,000 days");
+        assert_eq!(
+            DurationFormat::abbreviated().humanise(1_234_567 * DAY + MINUTE),
+            "1,234,567 days and 1 min"
+        );
+        assert_eq!(
+            DurationFormat::new()
+                .label(DurationUnit::Day, "Tag", "Tage")
+                .digit_separator("")
+                .humanise(12_345 * DAY),
+            "12345 Tage"
+        );
+        assert_eq!(
+            DurationFormat::new()
+                .digit_separator("\u{202f}")
+                .humanise(12_345 * DAY),
+            "12\u{202f}345 days"
+        );
+        assert_eq!(
+            humanise_duration_with_progress(12_345 * DAY + DAY / 2),
+            "12,345 days (50% into the next day)"
+        );
+        assert_eq!(
+            humanise_duration_ms(u128::MAX, true),
+            "3,938,453,320,844,195,178,974,243,141,571 days, 9 hours, 23 minutes, 31 seconds, and 455 milliseconds"
+        );
+        assert_eq!(
+            parse_duration("12,345 days, 1 hour"),
+            Ok(duration(12_345 * DAY + HOUR))
+        );
+        assert_eq!(
+            parse_duration("1,2345 days"),
+            Err(ParseDurationError::MissingUnit("1".to_string()))
+        );
+        assert_eq!(
+            parse_duration("1.5,000 days"),
+            Err(ParseDurationError::InvalidNumber("1.5,000".to_string()))
+        );
+    }
+
+    #[test]
+    fn grouped_rounded_days() {
+        assert_eq!(humanise_duration_rounded(12_345 * DAY), "12,345 days");
+        assert_eq!(
+            humanise_relative(duration(10_000 * DAY + HOUR), RelativeDirection::Past),
+            "about 10,000 days ago"
+        );
+    }
+
+    #[test]
+    fn decomposition() {
+        assert_eq!(decompose(0), DurationParts::default());
+        assert_eq!(
+            decompose(DAY - 1),
+            DurationParts {
+                days: 0,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                milliseconds: 999
+            }
+        );
+        assert_eq!(decompose(u128::MAX).days, u128::MAX / DAY);
+        assert!(!decompose(1).is_zero());
+    }
+
+    #[test]
+    fn relative_capped() {
+        use RelativeDirection::*;
+        let cap = Some(RelativeUnit::Minute);
+        assert_eq!(
+            humanise_relative_capped(duration(500), Past, cap),
+            "just now"
+        );
+        assert_eq!(
+            humanise_relative_capped(duration(MINUTE), Past, cap),
+            "1 minute ago"
+        );
+        assert_eq!(
+            humanise_relative_capped(duration(MINUTE + 1), Past, cap),
+            "over a minute ago"
+        );
+        assert_eq!(
+            humanise_relative_capped(Duration::MAX, Future, Some(RelativeUnit::Day)),
+            "in over a day"
+        );
+        let cap = Some(RelativeUnit::Year);
+        assert_eq!(
+            humanise_relative_capped(duration(437 * DAY), Past, cap),
+            "over a year ago"
+        );
+        assert_eq!(
+            humanise_relative_capped(duration(437 * DAY), Future, cap),
+            "in over a year"
+        );
+        assert_eq!(
+            humanise_relative_capped(duration(5 * WEEK), Past, Some(RelativeUnit::Month)),
+            "over a month ago"
         );
     }
 }
@@ -3,10 +3,19 @@
 //! `humanise` is a library that provides functions to convert raw data to not just human-readable strings, but humanised strings.
 
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 
-use std::fmt::Display;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
 
 #[cfg(feature = "durations")]
 #[cfg_attr(docsrs, doc(cfg(feature = "durations")))]
@@ -14,6 +23,99 @@ pub mod durations;
 #[cfg(feature = "durations")]
 #[cfg_attr(docsrs, doc(cfg(feature = "durations")))]
 pub use durations::*;
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub mod bytes;
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use bytes::*;
+
+/// A value that can be humanised.
+///
+/// This is a thin layer over the free functions in this crate, so that generic code can humanise values uniformly.
+/// It can be implemented for other types to give them a humanised form too.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::Humanise;
+///
+/// assert_eq!(["apples", "bananas"].humanise(), "apples and bananas");
+/// assert_eq!(vec![1, 2, 3].humanise(), "1, 2, and 3");
+/// ```
+pub trait Humanise {
+    /// Returns the humanised form of this value.
+    fn humanise(&self) -> String;
+}
+
+/// Humanises the slice using [`humanise_list`].
+impl<T> Humanise for [T]
+where
+    T: Display,
+{
+    fn humanise(&self) -> String {
+        humanise_list(self)
+    }
+}
+
+/// Humanises the vector using [`humanise_list`].
+impl<T> Humanise for Vec<T>
+where
+    T: Display,
+{
+    fn humanise(&self) -> String {
+        humanise_list(self)
+    }
+}
+
+/// A list that is displayed (and serialised) as its humanised form.
+///
+/// Both [`Display`] and [`Serialize`](serde::Serialize) use [`humanise_list`], so this can be used in types that are serialised
+/// to produce a humanised string instead of an array.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::HumanList;
+///
+/// let list = HumanList(vec!["apples", "bananas", "cherries"]);
+/// assert_eq!(list.to_string(), "apples, bananas, and cherries");
+/// assert_eq!(serde_json::to_string(&list).unwrap(), r#""apples, bananas, and cherries""#);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HumanList<T>(pub Vec<T>);
+
+#[cfg(feature = "serde")]
+impl<T> From<Vec<T>> for HumanList<T> {
+    fn from(list: Vec<T>) -> Self {
+        Self(list)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Display for HumanList<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&humanise_list(&self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for HumanList<T>
+where
+    T: Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&humanise_list(&self.0))
+    }
+}
 
 /// Humanise a list.
 ///
@@ -37,21 +139,186 @@ pub use durations::*;
 /// assert_eq!(humanise_list(&["apples", "bananas"]), "apples and bananas");
 /// assert_eq!(humanise_list(&["apples", "bananas", "strawberries"]), "apples, bananas, and strawberries");
 /// ```
+///
+/// To leave out the serial comma, use [`ListFormat::oxford_comma`]:
+///
+/// ```
+/// use humanise::ListFormat;
+///
+/// let format = ListFormat::new().oxford_comma(false);
+/// assert_eq!(format.format(&["apples", "bananas", "strawberries"]), "apples, bananas and strawberries");
+/// ```
 pub fn humanise_list<T>(list: &[T]) -> String
+where
+    T: Display,
+{
+    humanise_list_with(list, "and")
+}
+
+/// Humanise the items of an iterator (or anything else that can be iterated over, such as a [`Vec`] or a set).
+///
+/// The items are collected internally (the number of items has to be known to place the serial comma), and then humanised using [`humanise_list`].
+///
+/// # Return value
+///
+/// The same as [`humanise_list`].
+/// The items are listed in the order they are produced by the iterator, so collections without a defined order (such as [`HashSet`](https://doc.rust-lang.org/std/collections/struct.HashSet.html))
+/// produce their items in an arbitrary order.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_iter;
+/// use std::collections::BTreeSet;
+///
+/// assert_eq!(humanise_iter((1..=10).filter(|n| n % 3 == 0)), "3, 6, and 9");
+///
+/// let set = BTreeSet::from(["cherries", "apples", "bananas"]);
+/// assert_eq!(humanise_iter(&set), "apples, bananas, and cherries");
+/// ```
+pub fn humanise_iter<T>(iter: impl IntoIterator<Item = T>) -> String
+where
+    T: Display,
+{
+    humanise_list(&iter.into_iter().collect::<Vec<T>>())
+}
+
+/// Humanise a list, showing at most `max` elements and collapsing the rest into a count.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `max`: The maximum number of elements to show.
+///
+/// # Return value
+///
+/// If there are at most `max` elements in `list`, this is the same as [`humanise_list`].
+/// Otherwise, the first `max` elements are listed followed by the number of remaining elements as `N others` (or `1 other`).
+/// If `max` is 0, only the number of remaining elements is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_truncated;
+///
+/// let letters = ["a", "b", "c", "d", "e", "f"];
+/// assert_eq!(humanise_list_truncated(&letters, 3), "a, b, c, and 3 others");
+/// assert_eq!(humanise_list_truncated(&letters, 5), "a, b, c, d, e, and 1 other");
+/// assert_eq!(humanise_list_truncated(&letters, 6), "a, b, c, d, e, and f");
+/// assert_eq!(humanise_list_truncated(&letters, 0), "6 others");
+/// ```
+pub fn humanise_list_truncated<T>(list: &[T], max: usize) -> String
+where
+    T: Display,
+{
+    humanise_list_truncated_with_hidden(list, max).text
+}
+
+/// Humanise a list like [`humanise_list_truncated`], along with how many elements were left out.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_truncated_with_hidden;
+///
+/// let letters = ["a", "b", "c", "d", "e", "f"];
+/// let truncated = humanise_list_truncated_with_hidden(&letters, 3);
+/// assert_eq!(truncated.text, "a, b, c, and 3 others");
+/// assert_eq!(truncated.hidden, 3);
+/// assert_eq!(humanise_list_truncated_with_hidden(&letters, 6).hidden, 0);
+/// ```
+pub fn humanise_list_truncated_with_hidden<T>(list: &[T], max: usize) -> TruncatedList
+where
+    T: Display,
+{
+    ListFormat::new().truncate(max).format_truncated(list)
+}
+
+/// Humanise a list, using `conjunction` instead of `and`.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `conjunction`: The conjunction to join the last element with (e.g. `and`, `or`, or `nor`).
+///
+/// # Return value
+///
+/// The same as [`humanise_list`], except that `conjunction` is used in place of `and`, including for lists with two elements.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_with;
+///
+/// assert_eq!(humanise_list_with(&["foo"], "or"), "foo");
+/// assert_eq!(humanise_list_with(&["foo", "bar"], "or"), "foo or bar");
+/// assert_eq!(humanise_list_with(&["foo", "bar", "baz"], "or"), "foo, bar, or baz");
+/// ```
+pub fn humanise_list_with<T>(list: &[T], conjunction: &str) -> String
+where
+    T: Display,
+{
+    humanise_list_full(list, ", ", conjunction, conjunction, true)
+}
+
+/// Humanise a list, with full control over how the elements are joined.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `separator`: The separator put between elements (e.g. `, `).
+/// * `final_conjunction`: The conjunction put before the last element of lists with three or more elements (e.g. `and`).
+/// * `pair_conjunction`: The conjunction put between the elements of lists with exactly two elements (e.g. `and`).
+/// * `oxford`: Whether `separator` is also put before `final_conjunction` (i.e. whether to use a serial comma).
+///
+/// # Return value
+///
+/// If there are no elements in `list`, an empty string is returned.
+/// If there is one element in `list`, that element is formatted and returned.
+/// If there are two elements in `list`, those elements are formatted and concatenated with `pair_conjunction` in the middle.
+/// If there are more elements in `list`, the elements are joined with `separator`, except for the last element which is preceded by `final_conjunction`
+/// (and `separator` if `oxford` is `true`).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_full;
+///
+/// assert_eq!(humanise_list_full(&["A", "B", "C"], "; ", "and", "and", true), "A; B; and C");
+/// assert_eq!(humanise_list_full(&["A", "B", "C"], ", ", "and", "and", false), "A, B and C");
+/// assert_eq!(humanise_list_full(&["A", "B"], ", ", "and", "&", true), "A & B");
+/// ```
+pub fn humanise_list_full<T>(
+    list: &[T],
+    separator: &str,
+    final_conjunction: &str,
+    pair_conjunction: &str,
+    oxford: bool,
+) -> String
 where
     T: Display,
 {
     match list.len() {
         0 => "".to_string(),
         1 => list.first().unwrap().to_string(),
-        2 => format!("{} and {}", list.first().unwrap(), list.last().unwrap()),
+        2 => format!(
+            "{} {} {}",
+            list.first().unwrap(),
+            pair_conjunction,
+            list.last().unwrap()
+        ),
         _ => {
+            let last_prefix = if oxford {
+                format!("{}{} ", separator, final_conjunction)
+            } else {
+                format!(" {} ", final_conjunction)
+            };
             let mut str = String::new();
             for (i, value) in list.iter().enumerate() {
                 let prefix = match i {
                     0 => "",
-                    len if len == list.len() - 1 => ", and ",
-                    _ => ", ",
+                    len if len == list.len() - 1 => &last_prefix,
+                    _ => separator,
                 };
                 str = format!("{}{}{}", str, prefix, value);
             }
@@ -59,6 +326,921 @@ where
         }
     }
 }
+
+/// A reusable list format, for when the defaults of [`humanise_list`] aren't right.
+///
+/// The format is configured once using the builder methods, and [`ListFormat::format`] can then be called as many times as needed.
+/// The format created by [`ListFormat::new`] produces the same output as [`humanise_list`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::ListFormat;
+///
+/// let format = ListFormat::new().oxford_comma(false).conjunction("or");
+/// assert_eq!(format.format(&["foo", "bar", "baz"]), "foo, bar or baz");
+/// assert_eq!(format.format(&["foo", "bar"]), "foo or bar");
+///
+/// let format = ListFormat::new().separator("; ").pair_conjunction("&");
+/// assert_eq!(format.format(&["A", "B", "C"]), "A; B; and C");
+/// assert_eq!(format.format(&["A", "B"]), "A & B");
+///
+/// let format = ListFormat::new().conjunction("or").truncate(3);
+/// assert_eq!(format.format(&["a", "b", "c", "d", "e"]), "a, b, c, or 2 others");
+///
+/// let format = ListFormat::new().skip_blank(true);
+/// assert_eq!(format.format(&["a", "", "c"]), "a and c");
+///
+/// let format = ListFormat::new().truncate(2).overflow(|remaining| format!("{} more", remaining));
+/// assert_eq!(format.format(&["a", "b", "c", "d"]), "a, b, and 2 more");
+/// ```
+#[derive(Clone)]
+pub struct ListFormat {
+    separator: String,
+    conjunction: String,
+    pair_conjunction: Option<String>,
+    oxford_comma: bool,
+    truncate: Option<usize>,
+    skip_blank: bool,
+    overflow: Option<Arc<dyn Fn(usize) -> String + Send + Sync>>,
+}
+
+impl ListFormat {
+    /// Creates a new list format with the same behaviour as [`humanise_list`].
+    pub fn new() -> Self {
+        Self {
+            separator: ", ".to_string(),
+            conjunction: "and".to_string(),
+            pair_conjunction: None,
+            oxford_comma: true,
+            truncate: None,
+            skip_blank: false,
+            overflow: None,
+        }
+    }
+
+    /// Sets the separator put between elements. Defaults to `, `.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the conjunction put before the last element. Defaults to `and`.
+    ///
+    /// This is also used for lists with two elements, unless [`ListFormat::pair_conjunction`] is set.
+    pub fn conjunction(mut self, conjunction: impl Into<String>) -> Self {
+        self.conjunction = conjunction.into();
+        self
+    }
+
+    /// Sets the conjunction put between the elements of lists with exactly two elements.
+    /// Defaults to the same as [`ListFormat::conjunction`].
+    pub fn pair_conjunction(mut self, pair_conjunction: impl Into<String>) -> Self {
+        self.pair_conjunction = Some(pair_conjunction.into());
+        self
+    }
+
+    /// Sets whether the separator is also put before the conjunction in lists of three or more elements. Defaults to `true`.
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Sets the maximum number of elements to show. Defaults to `None` (showing every element).
+    ///
+    /// If there are more elements than `max`, the remaining elements are collapsed into a final element,
+    /// which is `N others` (or `1 other`) unless [`ListFormat::overflow`] is set.
+    pub fn truncate(mut self, max: impl Into<Option<usize>>) -> Self {
+        self.truncate = max.into();
+        self
+    }
+
+    /// Sets how the final element of a truncated list is phrased. Defaults to `N others` (or `1 other`).
+    ///
+    /// `overflow` receives the exact number of elements that were left out (which is always at least 1),
+    /// and its result is joined like any other element, so it is preceded by the conjunction.
+    /// This can be used to include the noun (`3 more files`) or to localise the phrasing.
+    pub fn overflow(mut self, overflow: impl Fn(usize) -> String + Send + Sync + 'static) -> Self {
+        self.overflow = Some(Arc::new(overflow));
+        self
+    }
+
+    /// Sets whether elements that are empty or only contain whitespace (once formatted) are left out. Defaults to `false`.
+    ///
+    /// Blank elements are left out before anything else is done, so the separators, conjunction, and truncation only consider the remaining elements.
+    pub fn skip_blank(mut self, skip_blank: bool) -> Self {
+        self.skip_blank = skip_blank;
+        self
+    }
+
+    /// Humanise `list` using this format.
+    ///
+    /// See [`humanise_list_full`] for how the list is joined.
+    /// To also find out how many elements were left out by [`ListFormat::truncate`], use [`ListFormat::format_truncated`].
+    pub fn format<T>(&self, list: &[T]) -> String
+    where
+        T: Display,
+    {
+        self.format_truncated(list).text
+    }
+
+    /// Humanise `list` using this format, along with how many elements were left out by [`ListFormat::truncate`].
+    ///
+    /// The text is the same as what [`ListFormat::format`] returns.
+    /// Blank elements skipped because of [`ListFormat::skip_blank`] aren't counted as hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use humanise::{ListFormat, TruncatedList};
+    ///
+    /// let format = ListFormat::new().truncate(2);
+    /// assert_eq!(
+    ///     format.format_truncated(&["a", "b", "c", "d"]),
+    ///     TruncatedList { text: "a, b, and 2 others".to_string(), hidden: 2 }
+    /// );
+    /// assert_eq!(format.format_truncated(&["a", "b"]).hidden, 0);
+    /// ```
+    pub fn format_truncated<T>(&self, list: &[T]) -> TruncatedList
+    where
+        T: Display,
+    {
+        if self.skip_blank {
+            let items: Vec<String> = list
+                .iter()
+                .map(ToString::to_string)
+                .filter(|item| !item.trim().is_empty())
+                .collect();
+            return self.truncate_and_join(&items);
+        }
+        self.truncate_and_join(list)
+    }
+
+    fn truncate_and_join<T>(&self, list: &[T]) -> TruncatedList
+    where
+        T: Display,
+    {
+        let (text, hidden) = match self.truncate {
+            Some(max) if list.len() > max => {
+                let remaining = list.len() - max;
+                let mut items: Vec<String> = list[..max].iter().map(ToString::to_string).collect();
+                items.push(match &self.overflow {
+                    Some(overflow) => overflow(remaining),
+                    None => format!(
+                        "{} {}",
+                        remaining,
+                        plural_suffix(remaining as u128, "other", false)
+                    ),
+                });
+                (self.join(&items), remaining)
+            }
+            _ => (self.join(list), 0),
+        };
+        TruncatedList { text, hidden }
+    }
+
+    fn join<T>(&self, list: &[T]) -> String
+    where
+        T: Display,
+    {
+        humanise_list_full(
+            list,
+            &self.separator,
+            &self.conjunction,
+            self.pair_conjunction.as_ref().unwrap_or(&self.conjunction),
+            self.oxford_comma,
+        )
+    }
+}
+
+impl Default for ListFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A humanised list along with how many of its elements were left out, as returned by [`ListFormat::format_truncated`],
+/// [`humanise_list_truncated_with_hidden`], and [`humanise_list_head_tail_with_hidden`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncatedList {
+    /// The humanised list.
+    pub text: String,
+    /// The number of elements that were left out because of truncation (0 if the list wasn't truncated).
+    pub hidden: usize,
+}
+
+impl core::fmt::Debug for ListFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ListFormat")
+            .field("separator", &self.separator)
+            .field("conjunction", &self.conjunction)
+            .field("pair_conjunction", &self.pair_conjunction)
+            .field("oxford_comma", &self.oxford_comma)
+            .field("truncate", &self.truncate)
+            .field("skip_blank", &self.skip_blank)
+            .field(
+                "overflow",
+                &self.overflow.as_ref().map(|_| "Fn(usize) -> String"),
+            )
+            .finish()
+    }
+}
+
+/// Two list formats are equal if all of their options are, where custom overflow phrasings are only equal if they are the same closure
+/// (shared by cloning the format).
+impl PartialEq for ListFormat {
+    fn eq(&self, other: &Self) -> bool {
+        self.separator == other.separator
+            && self.conjunction == other.conjunction
+            && self.pair_conjunction == other.pair_conjunction
+            && self.oxford_comma == other.oxford_comma
+            && self.truncate == other.truncate
+            && self.skip_blank == other.skip_blank
+            && match (&self.overflow, &other.overflow) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            }
+    }
+}
+
+impl Eq for ListFormat {}
+
+/// Humanise a list, wrapping it onto multiple lines so that the lines fit within `width`.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `width`: The maximum width of a line, in columns.
+///
+/// # Return value
+///
+/// The same text as [`humanise_list`], but with line breaks instead of some of the spaces so that each line is at most `width` characters long.
+/// Lines are only broken between elements (after the separating commas), and the conjunction is kept on the same line as the last element.
+/// Lines are filled greedily. If an element does not fit within `width` on its own, it is put on a line by itself.
+///
+/// With the `unicode` feature, the width is measured the way the text is displayed: each grapheme cluster takes up its displayed width,
+/// so combining characters take up no extra space and wide characters (such as CJK ideographs and most emoji) take up two columns.
+/// Without it, the width is measured in [`char`]s, which is only accurate for text without combining or wide characters.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_wrapped;
+///
+/// let fruit = ["apples", "bananas", "cherries", "dates"];
+/// assert_eq!(humanise_list_wrapped(&fruit, 80), "apples, bananas, cherries, and dates");
+/// assert_eq!(humanise_list_wrapped(&fruit, 20), "apples, bananas,\ncherries, and dates");
+/// assert_eq!(humanise_list_wrapped(&fruit, 10), "apples,\nbananas,\ncherries,\nand dates");
+/// ```
+pub fn humanise_list_wrapped<T>(list: &[T], width: usize) -> String
+where
+    T: Display,
+{
+    let segments: Vec<String> = list
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match list.len() {
+            len if len > 1 && i == len - 1 => format!("and {}", value),
+            1 | 2 => value.to_string(),
+            _ => format!("{},", value),
+        })
+        .collect();
+
+    let mut lines: Vec<String> = vec![];
+    for segment in segments {
+        match lines.last_mut() {
+            Some(line) if text_width(line) + 1 + text_width(&segment) <= width => {
+                line.push(' ');
+                line.push_str(&segment);
+            }
+            _ => lines.push(segment),
+        }
+    }
+    lines.join("\n")
+}
+
+/// Measures how many columns `text` takes up when displayed. See [`humanise_list_wrapped`].
+fn text_width(text: &str) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        use unicode_segmentation::UnicodeSegmentation;
+        use unicode_width::UnicodeWidthStr;
+
+        text.graphemes(true).map(UnicodeWidthStr::width).sum()
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        text.chars().count()
+    }
+}
+
+/// Humanise a list with a lead-in, leaving the lead-in out if the list is empty.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `prefix`: The lead-in to put before the list (e.g. `including`).
+///
+/// # Return value
+///
+/// If there are no elements in `list`, an empty string is returned.
+/// Otherwise, `prefix` followed by a space and the list humanised by [`humanise_list`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_prefixed;
+///
+/// assert_eq!(humanise_list_prefixed(&["apples"], "including"), "including apples");
+/// assert_eq!(humanise_list_prefixed(&["apples", "pears"], "including"), "including apples and pears");
+/// assert_eq!(humanise_list_prefixed::<&str>(&[], "including"), "");
+/// ```
+pub fn humanise_list_prefixed<T>(list: &[T], prefix: impl AsRef<str>) -> String
+where
+    T: Display,
+{
+    if list.is_empty() {
+        "".to_string()
+    } else {
+        format!("{} {}", prefix.as_ref(), humanise_list(list))
+    }
+}
+
+/// Humanise a list of optional items, skipping the ones that are `None`.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+///
+/// # Return value
+///
+/// The items that are `Some`, joined using [`humanise_list`].
+/// If every item is `None`, an empty string is returned (like [`humanise_list`] does for an empty list).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_options;
+///
+/// assert_eq!(humanise_options(&[Some("apples"), None, Some("bananas")]), "apples and bananas");
+/// assert_eq!(humanise_options::<&str>(&[None, None]), "");
+///
+/// // For results, convert them to options first.
+/// let results: [Result<u8, ()>; 3] = [Ok(1), Err(()), Ok(3)];
+/// let options: Vec<_> = results.iter().map(|result| result.as_ref().ok()).collect();
+/// assert_eq!(humanise_options(&options), "1 and 3");
+/// ```
+pub fn humanise_options<T>(list: &[Option<T>]) -> String
+where
+    T: Display,
+{
+    let present: Vec<&T> = list.iter().flatten().collect();
+    humanise_list(&present)
+}
+
+/// Humanise a list, only showing the first and last items if the list is long.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise.
+/// * `head`: The number of items to show from the start of the list.
+/// * `tail`: The number of items to show from the end of the list.
+///
+/// # Return value
+///
+/// If there are at most `head + tail` elements in `list`, this is the same as [`humanise_list`].
+/// Otherwise, the first `head` and last `tail` elements are listed with `…` in between them, and joined using [`humanise_list`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_head_tail;
+///
+/// let letters = ["A", "B", "C", "D", "E", "F"];
+/// assert_eq!(humanise_list_head_tail(&letters, 2, 2), "A, B, …, E, and F");
+/// assert_eq!(humanise_list_head_tail(&letters, 1, 1), "A, …, and F");
+/// assert_eq!(humanise_list_head_tail(&letters, 3, 3), "A, B, C, D, E, and F");
+/// ```
+pub fn humanise_list_head_tail<T>(list: &[T], head: usize, tail: usize) -> String
+where
+    T: Display,
+{
+    humanise_list_head_tail_with_hidden(list, head, tail).text
+}
+
+/// Humanise a list like [`humanise_list_head_tail`], along with how many elements were left out in place of the `…`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_head_tail_with_hidden;
+///
+/// let letters = ["A", "B", "C", "D", "E", "F"];
+/// let truncated = humanise_list_head_tail_with_hidden(&letters, 2, 2);
+/// assert_eq!(truncated.text, "A, B, …, E, and F");
+/// assert_eq!(truncated.hidden, 2);
+/// assert_eq!(humanise_list_head_tail_with_hidden(&letters, 3, 3).hidden, 0);
+/// ```
+pub fn humanise_list_head_tail_with_hidden<T>(list: &[T], head: usize, tail: usize) -> TruncatedList
+where
+    T: Display,
+{
+    if list.len() <= head.saturating_add(tail) {
+        return TruncatedList {
+            text: humanise_list(list),
+            hidden: 0,
+        };
+    }
+    let items: Vec<String> = list[..head]
+        .iter()
+        .map(ToString::to_string)
+        .chain(core::iter::once("…".to_string()))
+        .chain(list[list.len() - tail..].iter().map(ToString::to_string))
+        .collect();
+    TruncatedList {
+        text: humanise_list(&items),
+        hidden: list.len() - head - tail,
+    }
+}
+
+/// The numbering format used by [`humanise_ordered_steps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepNumbering {
+    /// Steps are numbered like `1.`.
+    Period,
+    /// Steps are numbered like `1)`.
+    Parenthesis,
+    /// Steps are numbered like `(1)`.
+    Enclosed,
+}
+
+impl StepNumbering {
+    fn format(&self, number: usize) -> String {
+        match self {
+            StepNumbering::Period => format!("{}.", number),
+            StepNumbering::Parenthesis => format!("{})", number),
+            StepNumbering::Enclosed => format!("({})", number),
+        }
+    }
+}
+
+/// The layout used by [`humanise_ordered_steps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStyle {
+    /// The numbered steps are joined like a regular list (see [`humanise_list`]).
+    Inline(StepNumbering),
+    /// Every numbered step is put on its own line.
+    MultiLine(StepNumbering),
+}
+
+/// Humanise a list of ordered steps, numbering each of them.
+///
+/// # Arguments
+///
+/// * `steps`: The steps to humanise, in order.
+/// * `style`: Whether to put the steps on one line or on separate lines, and how to number them.
+///
+/// # Return value
+///
+/// If there are no elements in `steps`, an empty string is returned.
+/// Otherwise, every step is prefixed with its number (starting at 1).
+/// With [`StepStyle::Inline`], the numbered steps are joined using [`humanise_list`].
+/// With [`StepStyle::MultiLine`], the numbered steps are joined with newlines (without a trailing newline).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_ordered_steps, StepNumbering, StepStyle};
+///
+/// let steps = ["preheat the oven", "mix the batter", "bake"];
+/// assert_eq!(
+///     humanise_ordered_steps(&steps, StepStyle::Inline(StepNumbering::Parenthesis)),
+///     "1) preheat the oven, 2) mix the batter, and 3) bake"
+/// );
+/// assert_eq!(
+///     humanise_ordered_steps(&steps, StepStyle::MultiLine(StepNumbering::Period)),
+///     "1. preheat the oven\n2. mix the batter\n3. bake"
+/// );
+/// ```
+pub fn humanise_ordered_steps<T>(steps: &[T], style: StepStyle) -> String
+where
+    T: Display,
+{
+    let numbering = match style {
+        StepStyle::Inline(numbering) | StepStyle::MultiLine(numbering) => numbering,
+    };
+    let numbered: Vec<String> = steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| format!("{} {}", numbering.format(i + 1), step))
+        .collect();
+    match style {
+        StepStyle::Inline(_) => humanise_list(&numbered),
+        StepStyle::MultiLine(_) => numbered.join("\n"),
+    }
+}
+
+/// Determines which identical items are counted together by [`humanise_list_counted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountMode {
+    /// Only runs of consecutive identical items are counted together.
+    Consecutive,
+    /// All identical items are counted together, regardless of where they are in the list.
+    /// The groups are ordered by the first appearance of each item.
+    All,
+}
+
+/// Humanise a list, collapsing identical items into a count.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise. The items should format as singular nouns, as they are pluralised using [`pluralise`].
+/// * `mode`: Which identical items to count together. See [`CountMode`].
+///
+/// # Return value
+///
+/// Every group of identical items formatted as `{count} {item}` (with `item` pluralised when `count` is not 1),
+/// joined using [`humanise_list`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::{humanise_list_counted, CountMode};
+///
+/// let fruit = ["apple", "apple", "apple", "banana"];
+/// assert_eq!(humanise_list_counted(&fruit, CountMode::Consecutive), "3 apples and 1 banana");
+///
+/// let rolls = [6, 6, 2, 6];
+/// assert_eq!(humanise_list_counted(&rolls, CountMode::Consecutive), "2 6s, 1 2, and 1 6");
+/// assert_eq!(humanise_list_counted(&rolls, CountMode::All), "3 6s and 1 2");
+/// ```
+pub fn humanise_list_counted<T>(list: &[T], mode: CountMode) -> String
+where
+    T: PartialEq + Display,
+{
+    let mut groups: Vec<(&T, u128)> = vec![];
+    for value in list {
+        let existing = match mode {
+            CountMode::Consecutive => groups.last_mut().filter(|(item, _)| *item == value),
+            CountMode::All => groups.iter_mut().find(|(item, _)| *item == value),
+        };
+        match existing {
+            Some((_, count)) => *count += 1,
+            None => groups.push((value, 1)),
+        }
+    }
+    let counted: Vec<String> = groups
+        .into_iter()
+        .map(|(item, count)| format!("{} {}", count, pluralise(count, item.to_string())))
+        .collect();
+    humanise_list(&counted)
+}
+
+/// Humanise a list, putting an indefinite article (`a` or `an`) before every item.
+///
+/// # Arguments
+///
+/// * `list`: The list to humanise. The items should format as nouns.
+///
+/// # Return value
+///
+/// The items, prefixed with their article, joined using [`humanise_list`].
+/// The article is chosen using [`article`].
+///
+/// Some items are left as-is, based on a simple heuristic:
+/// - items that already start with an article (`a`, `an`, or `the`), and
+/// - items that look plural, meaning that they end in `s` but not `ss` (so `apples` is left as-is, but `glass` is not).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_list_with_articles;
+///
+/// assert_eq!(humanise_list_with_articles(&["apple", "banana", "orange"]), "an apple, a banana, and an orange");
+/// assert_eq!(humanise_list_with_articles(&["the moon", "stars"]), "the moon and stars");
+/// ```
+pub fn humanise_list_with_articles<T>(list: &[T]) -> String
+where
+    T: Display,
+{
+    let items: Vec<String> = list
+        .iter()
+        .map(|item| {
+            let item = item.to_string();
+            let lowercase = item.to_lowercase();
+            let has_article = ["a ", "an ", "the "]
+                .iter()
+                .any(|article| lowercase.starts_with(article));
+            let is_plural = lowercase.ends_with('s') && !lowercase.ends_with("ss");
+            if has_article || is_plural {
+                item
+            } else {
+                with_article(item)
+            }
+        })
+        .collect();
+    humanise_list(&items)
+}
+
+/// Humanise a list of permissions as a summary of what can and cannot be done.
+///
+/// # Arguments
+///
+/// * `permissions`: The permissions, as pairs of the action and whether it is granted.
+///
+/// # Return value
+///
+/// If there are no permissions, an empty string is returned.
+/// If every permission is granted, `can` followed by the actions is returned, and if none are, `cannot` followed by the actions (joined with `or`).
+/// Otherwise, the granted actions come first, then `but not`, then the denied actions (joined with `or`).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_permissions;
+///
+/// assert_eq!(
+///     humanise_permissions(&[("read", true), ("write", true), ("delete", false)]),
+///     "can read and write but not delete"
+/// );
+/// assert_eq!(
+///     humanise_permissions(&[("read", true), ("write", true), ("delete", true)]),
+///     "can read, write, and delete"
+/// );
+/// assert_eq!(
+///     humanise_permissions(&[("read", false), ("write", false), ("delete", false)]),
+///     "cannot read, write, or delete"
+/// );
+/// ```
+pub fn humanise_permissions<T>(permissions: &[(T, bool)]) -> String
+where
+    T: Display,
+{
+    let (granted, denied): (Vec<_>, Vec<_>) = permissions.iter().partition(|(_, granted)| *granted);
+    let granted: Vec<&T> = granted.into_iter().map(|(action, _)| action).collect();
+    let denied: Vec<&T> = denied.into_iter().map(|(action, _)| action).collect();
+    match (granted.is_empty(), denied.is_empty()) {
+        (true, true) => "".to_string(),
+        (false, true) => format!("can {}", humanise_list(&granted)),
+        (true, false) => format!("cannot {}", humanise_list_with(&denied, "or")),
+        (false, false) => format!(
+            "can {} but not {}",
+            humanise_list(&granted),
+            humanise_list_with(&denied, "or")
+        ),
+    }
+}
+
+/// Humanise a count for a badge, capping it at `max`.
+///
+/// This is the same as calling [`humanise_badge_count_with`] with `+` as the suffix.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_badge_count;
+///
+/// assert_eq!(humanise_badge_count(5, 99, false), "5");
+/// assert_eq!(humanise_badge_count(150, 99, false), "99+");
+/// assert_eq!(humanise_badge_count(0, 99, true), "");
+/// ```
+pub fn humanise_badge_count(count: u128, max: u128, hide_zero: bool) -> String {
+    humanise_badge_count_with(count, max, "+", hide_zero)
+}
+
+/// Humanise a count for a badge, capping it at `max`.
+///
+/// # Arguments
+///
+/// * `count`: The count to humanise.
+/// * `max`: The largest count that is shown as-is.
+/// * `suffix`: The suffix added to `max` when `count` is larger than it.
+/// * `hide_zero`: Whether a `count` of zero should produce an empty string (i.e. no badge) instead of `0`.
+///
+/// # Return value
+///
+/// `count` if it is at most `max`, otherwise `max` followed by `suffix`.
+/// If `count` is zero and `hide_zero` is `true`, an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_badge_count_with;
+///
+/// assert_eq!(humanise_badge_count_with(1000, 999, " or more", false), "999 or more");
+/// assert_eq!(humanise_badge_count_with(999, 999, " or more", false), "999");
+/// assert_eq!(humanise_badge_count_with(0, 999, " or more", false), "0");
+/// ```
+pub fn humanise_badge_count_with(
+    count: u128,
+    max: u128,
+    suffix: impl AsRef<str>,
+    hide_zero: bool,
+) -> String {
+    if count == 0 && hide_zero {
+        "".to_string()
+    } else if count > max {
+        format!("{}{}", max, suffix.as_ref())
+    } else {
+        count.to_string()
+    }
+}
+
+/// Formats `n` with its digits grouped in threes, separated by commas.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::group_digits;
+///
+/// assert_eq!(group_digits(999), "999");
+/// assert_eq!(group_digits(1234), "1,234");
+/// assert_eq!(group_digits(1_234_567), "1,234,567");
+/// ```
+pub fn group_digits(n: u128) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Abbreviate a large count with a suffix, keeping one decimal place.
+///
+/// This is the same as calling [`humanise_count_with`] with a `decimals` of 1 and `trim` set to `true`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_count;
+///
+/// assert_eq!(humanise_count(999), "999");
+/// assert_eq!(humanise_count(1500), "1.5K");
+/// assert_eq!(humanise_count(2_000), "2K");
+/// assert_eq!(humanise_count(2_300_000), "2.3M");
+/// assert_eq!(humanise_count(7_800_000_000), "7.8B");
+/// assert_eq!(humanise_count(999_999), "1M");
+/// ```
+pub fn humanise_count(count: u128) -> String {
+    humanise_count_with(count, 1, true)
+}
+
+/// Abbreviate a large count with a suffix.
+///
+/// # Arguments
+///
+/// * `count`: The count to abbreviate.
+/// * `decimals`: The number of decimal places to keep.
+/// * `trim`: Whether to remove trailing zeros from the decimal places (along with the decimal point if they're all zero).
+///
+/// # Return value
+///
+/// If `count` is less than 1000, it is returned as-is.
+/// Otherwise, `count` is divided by the largest power of 1000 that fits in it, rounded half-up to `decimals` decimal places,
+/// and followed by the suffix for that power: `K` (thousand), `M` (million), `B` (billion), `T` (trillion), or `Q` (quadrillion).
+/// If rounding reaches 1000 of a unit, the next larger unit is used instead, so 999,999 with one decimal place is `1M` rather than `1000K`.
+/// Counts of a thousand quadrillion or more are still shown in quadrillions.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::humanise_count_with;
+///
+/// assert_eq!(humanise_count_with(1_234_567, 2, true), "1.23M");
+/// assert_eq!(humanise_count_with(2_000, 1, false), "2.0K");
+/// assert_eq!(humanise_count_with(1_250, 0, true), "1K");
+/// assert_eq!(humanise_count_with(1_050, 2, true), "1.05K");
+/// ```
+pub fn humanise_count_with(count: u128, decimals: usize, trim: bool) -> String {
+    const SUFFIXES: [&str; 5] = ["K", "M", "B", "T", "Q"];
+    /// The units are at most 10^15, so any further decimal places are always zero.
+    const MAX_DECIMALS: usize = 15;
+    if count < 1000 {
+        return count.to_string();
+    }
+
+    let significant = decimals.min(MAX_DECIMALS);
+    let scale = 10u128.pow(significant as u32);
+    let mut index = 0;
+    while index < SUFFIXES.len() - 1 && count >= 1000u128.pow(index as u32 + 2) {
+        index += 1;
+    }
+    let round = |index: usize| {
+        let unit = 1000u128.pow(index as u32 + 1);
+        let whole = count / unit;
+        let fraction = (count % unit * scale + unit / 2) / unit;
+        if fraction == scale {
+            (whole + 1, 0)
+        } else {
+            (whole, fraction)
+        }
+    };
+    let (mut whole, mut fraction) = round(index);
+    if whole >= 1000 && index < SUFFIXES.len() - 1 {
+        index += 1;
+        (whole, fraction) = round(index);
+    }
+
+    let mut fraction = format!("{:0width$}", fraction, width = significant);
+    fraction.push_str(&"0".repeat(decimals - significant));
+    if trim {
+        fraction.truncate(fraction.trim_end_matches('0').len());
+    }
+    if decimals == 0 || fraction.is_empty() {
+        format!("{}{}", whole, SUFFIXES[index])
+    } else {
+        format!("{}.{}{}", whole, fraction, SUFFIXES[index])
+    }
+}
+
+/// Formats a fractional count along with a noun, pluralising the noun if it is supposed to be.
+///
+/// # Arguments
+///
+/// * `value`: The count.
+/// * `word`: The noun to pluralise, which is done using [`pluralise`].
+/// * `precision`: The number of decimal places to format `value` with. If `None`, `value` is formatted as short as possible.
+///
+/// # Return value
+///
+/// `value` followed by `word`.
+/// In English, everything except exactly 1 is plural (including 0 and fractions), so `word` is only left singular if `value` is exactly `1.0`.
+/// Note that this is decided using `value` itself rather than its formatted form, so `0.999` with a precision of 0 is `1 items`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::count_noun_float;
+///
+/// assert_eq!(count_noun_float(2.5, "hour", None), "2.5 hours");
+/// assert_eq!(count_noun_float(1.0, "hour", None), "1 hour");
+/// assert_eq!(count_noun_float(1.0, "hour", Some(1)), "1.0 hour");
+/// assert_eq!(count_noun_float(0.5, "hour", Some(2)), "0.50 hours");
+/// ```
+pub fn count_noun_float(value: f64, word: impl AsRef<str>, precision: Option<usize>) -> String {
+    let count = if value == 1.0 { 1 } else { 0 };
+    let word = pluralise(count, word);
+    match precision {
+        Some(precision) => format!("{:.*} {}", precision, value, word),
+        None => format!("{} {}", value, word),
+    }
+}
+
+/// Formats a count along with a noun, grouping the digits of the count and pluralising the noun if it is supposed to be.
+///
+/// See [`count_noun_with`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::count_noun;
+///
+/// assert_eq!(count_noun(1, "file"), "1 file");
+/// assert_eq!(count_noun(1_200_000, "file"), "1,200,000 files");
+/// assert_eq!(count_noun(3, "box"), "3 boxes");
+/// ```
+pub fn count_noun(count: u128, word: impl AsRef<str>) -> String {
+    count_noun_with(count, word, None)
+}
+
+/// Formats a count along with a noun, pluralising the noun if it is supposed to be.
+///
+/// # Arguments
+///
+/// * `count`: The count.
+/// * `word`: The noun to pluralise, which is done using [`pluralise`].
+/// * `abbreviate`: The number of decimal places to abbreviate `count` to using [`humanise_count_with`] (with trailing zeros trimmed).
+///   If `None`, `count` is shown in full with its digits grouped using [`group_digits`].
+///
+/// # Return value
+///
+/// `count` followed by `word`.
+/// Whether `word` is plural is always decided using the true `count` rather than its abbreviated form,
+/// so 1,000 is `1K files` and 1,040 abbreviated to no decimal places is also `1K files`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::count_noun_with;
+///
+/// assert_eq!(count_noun_with(1_200_000, "file", Some(1)), "1.2M files");
+/// assert_eq!(count_noun_with(1_000_000, "file", Some(1)), "1M files");
+/// assert_eq!(count_noun_with(1, "file", Some(1)), "1 file");
+/// assert_eq!(count_noun_with(12_345, "city", None), "12,345 cities");
+/// ```
+pub fn count_noun_with(count: u128, word: impl AsRef<str>, abbreviate: Option<usize>) -> String {
+    let count_str = match abbreviate {
+        Some(decimals) => humanise_count_with(count, decimals, true),
+        None => group_digits(count),
+    };
+    format!("{} {}", count_str, pluralise(count, word))
+}
+
 /// Adds a plural suffix if there is supposed to be one.
 ///
 /// # Arguments
@@ -66,7 +1248,7 @@ where
 /// * `count`: The number of items. The suffix is applied if this is not 1.
 /// * `word`: The word to apply the suffix to.
 /// * `opposite`: Determines the suffix to use. `false` makes it so that plural gets the `s` suffix - `true` would have singular get the prefix instead.
-///               This is included so that this function can be used with both nouns and verbs.
+///   This is included so that this function can be used with both nouns and verbs.
 ///
 /// # Return value
 ///
@@ -91,7 +1273,6 @@ where
 /// ```
 #[inline]
 pub fn plural_suffix(count: u128, word: impl AsRef<str>, opposite: bool) -> String {
-    let count = count.into();
     let suffix = match count {
         1 => {
             if opposite {
@@ -111,6 +1292,522 @@ pub fn plural_suffix(count: u128, word: impl AsRef<str>, opposite: bool) -> Stri
     format!("{}{}", word.as_ref(), suffix)
 }
 
+/// Picks the indefinite article (`a` or `an`) to put before `word`.
+///
+/// # Arguments
+///
+/// * `word`: The word that follows the article.
+///
+/// # Return value
+///
+/// If `word` (ignoring case) starts with one of the prefixes in [`ARTICLE_EXCEPTIONS`], the article for that prefix is returned.
+/// Otherwise, `an` is returned if `word` starts with a vowel (`a`, `e`, `i`, `o`, or `u`), and `a` if it doesn't.
+///
+/// This is based on spelling rather than pronunciation, so words that aren't covered by the exceptions might get the wrong article
+/// (as can abbreviations like `FBI`).
+///
+/// # Examples
+///
+/// ```
+/// use humanise::article;
+///
+/// assert_eq!(article("apple"), "an");
+/// assert_eq!(article("banana"), "a");
+/// assert_eq!(article("hour"), "an");
+/// assert_eq!(article("university"), "a");
+/// ```
+pub fn article(word: impl AsRef<str>) -> &'static str {
+    let lowercase = word.as_ref().to_lowercase();
+    if let Some((_, article)) = ARTICLE_EXCEPTIONS
+        .iter()
+        .find(|(prefix, _)| lowercase.starts_with(prefix))
+    {
+        return article;
+    }
+    match lowercase.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
+        _ => "a",
+    }
+}
+
+/// The word prefixes that [`article`] doesn't pick the article for based on the first letter, as pairs of the prefix and the article.
+pub const ARTICLE_EXCEPTIONS: &[(&str, &str)] = &[
+    ("heir", "an"),
+    ("honest", "an"),
+    ("honor", "an"),
+    ("honour", "an"),
+    ("hour", "an"),
+    ("eulog", "a"),
+    ("euro", "a"),
+    ("ewe", "a"),
+    ("once", "a"),
+    ("unicorn", "a"),
+    ("uniform", "a"),
+    ("union", "a"),
+    ("unique", "a"),
+    ("unit", "a"),
+    ("univers", "a"),
+    ("use", "a"),
+    ("usual", "a"),
+    ("utensil", "a"),
+    ("utili", "a"),
+];
+
+/// Puts the indefinite article (`a` or `an`) before `word`.
+///
+/// See [`article`] for how the article is picked.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::with_article;
+///
+/// assert_eq!(with_article("apple"), "an apple");
+/// assert_eq!(with_article("banana"), "a banana");
+/// assert_eq!(with_article("honest mistake"), "an honest mistake");
+/// ```
+pub fn with_article(word: impl AsRef<str>) -> String {
+    let word = word.as_ref();
+    format!("{} {}", article(word), word)
+}
+
+/// Formats `n` with its English ordinal suffix.
+///
+/// # Arguments
+///
+/// * `n`: The number to format.
+///
+/// # Return value
+///
+/// `n` followed by `st`, `nd`, `rd`, or `th`.
+/// Numbers ending in 11, 12, or 13 always get `th`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::ordinal;
+///
+/// assert_eq!(ordinal(1), "1st");
+/// assert_eq!(ordinal(2), "2nd");
+/// assert_eq!(ordinal(3), "3rd");
+/// assert_eq!(ordinal(4), "4th");
+/// assert_eq!(ordinal(11), "11th");
+/// assert_eq!(ordinal(112), "112th");
+/// assert_eq!(ordinal(21), "21st");
+/// ```
+pub fn ordinal(n: u128) -> String {
+    format!("{}{}", n, ordinal_suffix(n))
+}
+
+/// Picks the English ordinal suffix (`st`, `nd`, `rd`, or `th`) for `n`.
+fn ordinal_suffix(n: u128) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Spells out `n` as an English ordinal word.
+///
+/// # Arguments
+///
+/// * `n`: The number to spell out.
+///
+/// # Return value
+///
+/// The ordinal word for `n` (e.g. `first`, `twelfth`, or `forty-second`) if `n` is less than 100.
+/// Larger numbers are formatted like [`ordinal`] does.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::ordinal_word;
+///
+/// assert_eq!(ordinal_word(1), "first");
+/// assert_eq!(ordinal_word(2), "second");
+/// assert_eq!(ordinal_word(3), "third");
+/// assert_eq!(ordinal_word(12), "twelfth");
+/// assert_eq!(ordinal_word(20), "twentieth");
+/// assert_eq!(ordinal_word(42), "forty-second");
+/// assert_eq!(ordinal_word(100), "100th");
+/// ```
+pub fn ordinal_word(n: u128) -> String {
+    const UNITS: [&str; 20] = [
+        "zeroth",
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+    ];
+    const TENS: [(&str, &str); 8] = [
+        ("twenty", "twentieth"),
+        ("thirty", "thirtieth"),
+        ("forty", "fortieth"),
+        ("fifty", "fiftieth"),
+        ("sixty", "sixtieth"),
+        ("seventy", "seventieth"),
+        ("eighty", "eightieth"),
+        ("ninety", "ninetieth"),
+    ];
+    match n {
+        0..=19 => UNITS[n as usize].to_string(),
+        20..=99 => {
+            let (cardinal, ordinal) = TENS[(n / 10 - 2) as usize];
+            match n % 10 {
+                0 => ordinal.to_string(),
+                units => format!("{}-{}", cardinal, UNITS[units as usize]),
+            }
+        }
+        _ => ordinal(n),
+    }
+}
+
+/// Spells out `n` in English words.
+///
+/// This is the same as calling [`number_to_words_with`] with `british_and` set to `false`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::number_to_words;
+///
+/// assert_eq!(number_to_words(0), "zero");
+/// assert_eq!(number_to_words(21), "twenty-one");
+/// assert_eq!(number_to_words(1234), "one thousand two hundred thirty-four");
+/// ```
+pub fn number_to_words(n: u128) -> String {
+    number_to_words_with(n, false)
+}
+
+/// Spells out `n` in English words.
+///
+/// # Arguments
+///
+/// * `n`: The number to spell out.
+/// * `british_and`: Whether to insert `and` before the tens and units, in the British style
+///   (e.g. `one hundred and five` or `one thousand and twelve`).
+///
+/// # Return value
+///
+/// `n` written out in words, using the short scale (so a billion is 10<sup>9</sup>).
+/// Tens and units are joined with a hyphen (`forty-two`), and every group of three digits is followed by its scale word,
+/// up to `undecillion` (10<sup>36</sup>), which covers the full range of [`u128`].
+/// Groups that are zero are left out.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::number_to_words_with;
+///
+/// assert_eq!(number_to_words_with(105, true), "one hundred and five");
+/// assert_eq!(number_to_words_with(105, false), "one hundred five");
+/// assert_eq!(number_to_words_with(2_000_012, true), "two million and twelve");
+/// assert_eq!(number_to_words_with(7_000_300, true), "seven million three hundred");
+/// ```
+pub fn number_to_words_with(n: u128, british_and: bool) -> String {
+    const SCALES: [&str; 13] = [
+        "",
+        "thousand",
+        "million",
+        "billion",
+        "trillion",
+        "quadrillion",
+        "quintillion",
+        "sextillion",
+        "septillion",
+        "octillion",
+        "nonillion",
+        "decillion",
+        "undecillion",
+    ];
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    let mut groups = vec![];
+    let mut remaining = n;
+    while remaining > 0 {
+        groups.push((remaining % 1000) as usize);
+        remaining /= 1000;
+    }
+
+    let mut words = vec![];
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if british_and && scale == 0 && group < 100 && groups.len() > 1 {
+            words.push("and".to_string());
+        }
+        words.push(hundreds_to_words(group, british_and));
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// Spells out a number from 1 to 999 for [`number_to_words_with`].
+fn hundreds_to_words(n: usize, british_and: bool) -> String {
+    const UNITS: [&str; 20] = [
+        "",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    let below_hundred = match n % 100 {
+        0 => None,
+        units @ 1..=19 => Some(UNITS[units].to_string()),
+        tens => Some(match tens % 10 {
+            0 => TENS[tens / 10 - 2].to_string(),
+            units => format!("{}-{}", TENS[tens / 10 - 2], UNITS[units]),
+        }),
+    };
+    match (n / 100, below_hundred) {
+        (0, Some(below_hundred)) => below_hundred,
+        (hundreds, None) => format!("{} hundred", UNITS[hundreds]),
+        (hundreds, Some(below_hundred)) => format!(
+            "{} hundred {}{}",
+            UNITS[hundreds],
+            if british_and { "and " } else { "" },
+            below_hundred
+        ),
+    }
+}
+
+/// Pluralises `word` following the regular English spelling rules, if there is supposed to be a plural.
+///
+/// This is the same as calling [`pluralise_with`] with `f_to_ves` set to `false`.
+///
+/// # Examples
+///
+/// ```
+/// use humanise::pluralise;
+///
+/// assert_eq!(pluralise(1, "box"), "box");
+/// assert_eq!(pluralise(2, "box"), "boxes");
+/// assert_eq!(pluralise(2, "city"), "cities");
+/// assert_eq!(pluralise(2, "day"), "days");
+/// assert_eq!(pluralise(2, "leaf"), "leafs");
+/// ```
+pub fn pluralise(count: u128, word: impl AsRef<str>) -> String {
+    pluralise_with(count, word, false)
+}
+
+/// Pluralises `word` following the regular English spelling rules, if there is supposed to be a plural.
+///
+/// # Arguments
+///
+/// * `count`: The number of items. `word` is pluralised if this is not 1.
+/// * `word`: The singular word to pluralise.
+/// * `f_to_ves`: Whether words ending in `f` or `fe` should have that ending replaced with `ves` (e.g. `leaf` becomes `leaves`).
+///   This is optional since plenty of words don't follow it (e.g. `roof` becomes `roofs`).
+///
+/// # Return value
+///
+/// If `count` is 1, `word` is returned unchanged. Otherwise, the first rule that matches is applied:
+/// - words ending in `s`, `x`, `z`, `ch`, or `sh` get `es` (`bush` becomes `bushes`),
+/// - words ending in a consonant followed by `y` have the `y` replaced with `ies` (`city` becomes `cities`),
+/// - if `f_to_ves` is `true`, words ending in `f` or `fe` have that replaced with `ves` (`knife` becomes `knives`), and
+/// - all other words get `s`.
+///
+/// Before these rules, `word` is looked up in [`IRREGULAR_PLURALS`], and if it is found there the irregular plural is used instead.
+/// If `word` is capitalised (or fully uppercase), the irregular plural is capitalised (or uppercased) to match.
+/// To add your own irregular plurals, use a [`Pluraliser`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::pluralise_with;
+///
+/// assert_eq!(pluralise_with(2, "leaf", true), "leaves");
+/// assert_eq!(pluralise_with(2, "knife", true), "knives");
+/// assert_eq!(pluralise_with(2, "leaf", false), "leafs");
+/// assert_eq!(pluralise_with(1, "leaf", true), "leaf");
+/// assert_eq!(pluralise_with(2, "Mouse", true), "Mice");
+/// ```
+pub fn pluralise_with(count: u128, word: impl AsRef<str>, f_to_ves: bool) -> String {
+    let word = word.as_ref();
+    if count == 1 {
+        return word.to_string();
+    }
+    let lowercase = word.to_lowercase();
+    match IRREGULAR_PLURALS
+        .iter()
+        .find(|(singular, _)| *singular == lowercase)
+    {
+        Some((_, plural)) => match_case(word, plural),
+        None => pluralise_regular(word, f_to_ves),
+    }
+}
+
+/// The irregular plurals that [`pluralise`] and [`pluralise_with`] know about, as pairs of the singular and the plural.
+pub const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("deer", "deer"),
+    ("fish", "fish"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("louse", "lice"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("series", "series"),
+    ("sheep", "sheep"),
+    ("species", "species"),
+    ("tooth", "teeth"),
+    ("woman", "women"),
+];
+
+/// The grammatical gender of a noun, for languages where numbers and articles agree with the noun they count.
+///
+/// English output doesn't use this, but custom formats such as `DurationFormat::count_form` (with the `durations` feature) pass it along
+/// so that a French or Spanish format can choose between forms like `un` and `une`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// Masculine nouns (e.g. French `jour`).
+    Masculine,
+    /// Feminine nouns (e.g. French `heure`).
+    Feminine,
+    /// Neuter nouns, and nouns in languages without grammatical gender.
+    #[default]
+    Neuter,
+}
+
+/// A pluraliser with its own table of irregular plurals, in addition to [`IRREGULAR_PLURALS`].
+///
+/// # Examples
+///
+/// ```
+/// use humanise::Pluraliser;
+///
+/// let pluraliser = Pluraliser::new().irregular("die", "dice").f_to_ves(true);
+/// assert_eq!(pluraliser.pluralise(2, "die"), "dice");
+/// assert_eq!(pluraliser.pluralise(1, "die"), "die");
+/// assert_eq!(pluraliser.pluralise(2, "child"), "children");
+/// assert_eq!(pluraliser.pluralise(2, "leaf"), "leaves");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pluraliser {
+    irregular: BTreeMap<String, String>,
+    f_to_ves: bool,
+}
+
+impl Pluraliser {
+    /// Creates a new pluraliser without any irregular plurals of its own, which does not replace `f` with `ves`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an irregular plural. Irregular plurals added here take precedence over [`IRREGULAR_PLURALS`].
+    ///
+    /// `singular` is matched case-insensitively.
+    pub fn irregular(mut self, singular: impl AsRef<str>, plural: impl Into<String>) -> Self {
+        self.insert(singular, plural);
+        self
+    }
+
+    /// Adds an irregular plural to an existing pluraliser. See [`Pluraliser::irregular`].
+    pub fn insert(&mut self, singular: impl AsRef<str>, plural: impl Into<String>) {
+        self.irregular
+            .insert(singular.as_ref().to_lowercase(), plural.into());
+    }
+
+    /// Sets whether words ending in `f` or `fe` should have that ending replaced with `ves`. See [`pluralise_with`].
+    pub fn f_to_ves(mut self, f_to_ves: bool) -> Self {
+        self.f_to_ves = f_to_ves;
+        self
+    }
+
+    /// Pluralises `word` if `count` is not 1.
+    ///
+    /// This works like [`pluralise_with`], except that the irregular plurals of this pluraliser are checked first.
+    pub fn pluralise(&self, count: u128, word: impl AsRef<str>) -> String {
+        let word = word.as_ref();
+        if count == 1 {
+            return word.to_string();
+        }
+        match self.irregular.get(&word.to_lowercase()) {
+            Some(plural) => match_case(word, plural),
+            None => pluralise_with(count, word, self.f_to_ves),
+        }
+    }
+}
+
+/// Changes the case of `plural` to match `singular`: uppercase if `singular` is fully uppercase, capitalised if `singular` is capitalised.
+fn match_case(singular: &str, plural: &str) -> String {
+    let has_letters = singular.chars().any(char::is_alphabetic);
+    if has_letters && singular.chars().count() > 1 && !singular.chars().any(char::is_lowercase) {
+        plural.to_uppercase()
+    } else if singular.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = plural.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    } else {
+        plural.to_string()
+    }
+}
+
+/// Applies the regular spelling rules of [`pluralise_with`] to `word`.
+fn pluralise_regular(word: &str, f_to_ves: bool) -> String {
+    let lowercase = word.to_lowercase();
+    let is_consonant = |c: char| c.is_alphabetic() && !"aeiou".contains(c);
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| lowercase.ends_with(ending))
+    {
+        format!("{}es", word)
+    } else if lowercase.ends_with('y') && lowercase.chars().rev().nth(1).is_some_and(is_consonant) {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if f_to_ves && lowercase.ends_with("fe") {
+        format!("{}ves", &word[..word.len() - 2])
+    } else if f_to_ves && lowercase.ends_with('f') {
+        format!("{}ves", &word[..word.len() - 1])
+    } else {
+        format!("{}s", word)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +1824,487 @@ mod tests {
         assert_eq!(plural_suffix(1, "make", true), "makes");
         assert_eq!(plural_suffix(2, "make", true), "make");
     }
+    #[test]
+    fn ordered_steps() {
+        assert_eq!(
+            humanise_ordered_steps::<&str>(&[], StepStyle::Inline(StepNumbering::Period)),
+            ""
+        );
+        assert_eq!(
+            humanise_ordered_steps(&["bake"], StepStyle::Inline(StepNumbering::Enclosed)),
+            "(1) bake"
+        );
+        assert_eq!(
+            humanise_ordered_steps(&["mix", "bake"], StepStyle::Inline(StepNumbering::Period)),
+            "1. mix and 2. bake"
+        );
+        assert_eq!(
+            humanise_ordered_steps(
+                &["mix", "bake"],
+                StepStyle::MultiLine(StepNumbering::Enclosed)
+            ),
+            "(1) mix\n(2) bake"
+        );
+    }
+
+    #[test]
+    fn list_counted() {
+        assert_eq!(humanise_list_counted::<&str>(&[], CountMode::All), "");
+        assert_eq!(
+            humanise_list_counted(&["die"], CountMode::Consecutive),
+            "1 die"
+        );
+        assert_eq!(
+            humanise_list_counted(&["a", "b", "a", "a"], CountMode::Consecutive),
+            "1 a, 1 b, and 2 as"
+        );
+        assert_eq!(
+            humanise_list_counted(&["a", "b", "a", "a"], CountMode::All),
+            "3 as and 1 b"
+        );
+        assert_eq!(
+            humanise_list_counted(&["box", "box", "child"], CountMode::All),
+            "2 boxes and 1 child"
+        );
+        assert_eq!(
+            humanise_list_counted(&["mouse", "mouse"], CountMode::Consecutive),
+            "2 mice"
+        );
+    }
+
+    #[test]
+    fn badge_count() {
+        assert_eq!(humanise_badge_count(0, 99, false), "0");
+        assert_eq!(humanise_badge_count(0, 99, true), "");
+        assert_eq!(humanise_badge_count(99, 99, true), "99");
+        assert_eq!(humanise_badge_count(100, 99, true), "99+");
+        assert_eq!(humanise_badge_count(1, 0, true), "0+");
+    }
+
+    #[test]
+    fn list_with_articles() {
+        assert_eq!(humanise_list_with_articles::<&str>(&[]), "");
+        assert_eq!(humanise_list_with_articles(&["Egg"]), "an Egg");
+        assert_eq!(
+            humanise_list_with_articles(&["An owl", "glass", "boots"]),
+            "An owl, a glass, and boots"
+        );
+    }
+
+    #[test]
+    fn permissions() {
+        assert_eq!(humanise_permissions::<&str>(&[]), "");
+        assert_eq!(humanise_permissions(&[("read", true)]), "can read");
+        assert_eq!(
+            humanise_permissions(&[("read", false), ("write", false)]),
+            "cannot read or write"
+        );
+        assert_eq!(
+            humanise_permissions(&[("delete", false), ("read", true), ("share", false)]),
+            "can read but not delete or share"
+        );
+    }
+
+    #[test]
+    fn count_noun_float_plurality() {
+        assert_eq!(count_noun_float(0.0, "item", None), "0 items");
+        assert_eq!(count_noun_float(1.0, "item", None), "1 item");
+        assert_eq!(count_noun_float(0.5, "item", None), "0.5 items");
+        assert_eq!(count_noun_float(2.0, "item", None), "2 items");
+        assert_eq!(count_noun_float(-1.0, "item", None), "-1 items");
+        assert_eq!(count_noun_float(2.0, "box", None), "2 boxes");
+        assert_eq!(count_noun_float(1.5, "city", None), "1.5 cities");
+        assert_eq!(count_noun_float(1.0, "city", None), "1 city");
+    }
+    #[test]
+    fn count_noun_abbreviated() {
+        assert_eq!(count_noun_with(1_000_000, "file", Some(1)), "1M files");
+        assert_eq!(count_noun_with(1_040, "file", Some(0)), "1K files");
+        assert_eq!(count_noun_with(999, "file", Some(1)), "999 files");
+        assert_eq!(count_noun_with(0, "box", Some(1)), "0 boxes");
+        assert_eq!(count_noun(1, "city"), "1 city");
+        assert_eq!(count_noun(1_000_000, "file"), "1,000,000 files");
+    }
+
+    #[test]
+    fn count_noun_float_precision() {
+        assert_eq!(count_noun_float(0.0, "item", Some(1)), "0.0 items");
+        assert_eq!(count_noun_float(1.0, "item", Some(2)), "1.00 item");
+        assert_eq!(count_noun_float(2.25, "item", Some(1)), "2.2 items");
+        assert_eq!(count_noun_float(0.999, "item", Some(0)), "1 items");
+    }
+
+    #[test]
+    fn list_head_tail() {
+        let list = [1, 2, 3, 4, 5];
+        assert_eq!(humanise_list_head_tail(&list, 0, 0), "…");
+        assert_eq!(humanise_list_head_tail(&list, 2, 0), "1, 2, and …");
+        assert_eq!(humanise_list_head_tail(&list, 0, 1), "… and 5");
+        assert_eq!(humanise_list_head_tail(&list, 4, 0), "1, 2, 3, 4, and …");
+        assert_eq!(
+            humanise_list_head_tail(&list, usize::MAX, 1),
+            "1, 2, 3, 4, and 5"
+        );
+    }
+
+    #[test]
+    fn hidden_counts() {
+        let list = [1, 2, 3, 4, 5];
+        assert_eq!(humanise_list_head_tail_with_hidden(&list, 0, 0).hidden, 5);
+        assert_eq!(humanise_list_head_tail_with_hidden(&list, 1, 1).hidden, 3);
+        assert_eq!(
+            humanise_list_head_tail_with_hidden(&list, usize::MAX, 1).hidden,
+            0
+        );
+        assert_eq!(
+            humanise_list_truncated_with_hidden(&list, 0),
+            TruncatedList {
+                text: "5 others".to_string(),
+                hidden: 5
+            }
+        );
+        assert_eq!(humanise_list_truncated_with_hidden(&list, 5).hidden, 0);
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(humanise_options::<&str>(&[]), "");
+        assert_eq!(humanise_options(&[None, Some(1)]), "1");
+        assert_eq!(
+            humanise_options(&[Some(1), Some(2), None, Some(3)]),
+            "1, 2, and 3"
+        );
+    }
+
+    #[test]
+    fn list_full() {
+        assert_eq!(humanise_list_full::<&str>(&[], "; ", "or", "or", true), "");
+        assert_eq!(humanise_list_full(&["a"], "; ", "or", "or", true), "a");
+        assert_eq!(
+            humanise_list_full(&["a", "b"], "; ", "or", "or", true),
+            "a or b"
+        );
+        assert_eq!(
+            humanise_list_full(&["a", "b", "c", "d"], "; ", "or", "or", false),
+            "a; b; c or d"
+        );
+        assert_eq!(
+            humanise_list_full(&["a", "b", "c"], " / ", "and", "and", true),
+            "a / b / and c"
+        );
+    }
+
+    #[test]
+    fn list_prefixed() {
+        assert_eq!(humanise_list_prefixed::<u8>(&[], "with"), "");
+        assert_eq!(
+            humanise_list_prefixed(&[1, 2, 3], "with"),
+            "with 1, 2, and 3"
+        );
+    }
+
+    #[test]
+    fn ordinals() {
+        let ordinals: Vec<_> = [0, 1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111, 112]
+            .into_iter()
+            .map(ordinal)
+            .collect();
+        assert_eq!(
+            ordinals,
+            [
+                "0th", "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd",
+                "101st", "111th", "112th"
+            ]
+        );
+        assert_eq!(ordinal(u128::MAX), format!("{}th", u128::MAX));
+    }
+    #[test]
+    fn ordinal_words() {
+        assert_eq!(ordinal_word(0), "zeroth");
+        assert_eq!(ordinal_word(13), "thirteenth");
+        assert_eq!(ordinal_word(19), "nineteenth");
+        assert_eq!(ordinal_word(21), "twenty-first");
+        assert_eq!(ordinal_word(90), "ninetieth");
+        assert_eq!(ordinal_word(99), "ninety-ninth");
+        assert_eq!(ordinal_word(101), "101st");
+    }
+
+    #[test]
+    fn list_wrapped() {
+        assert_eq!(humanise_list_wrapped::<&str>(&[], 10), "");
+        assert_eq!(humanise_list_wrapped(&["apples"], 0), "apples");
+        assert_eq!(
+            humanise_list_wrapped(&["apples", "bananas"], 11),
+            "apples\nand bananas"
+        );
+        assert_eq!(
+            humanise_list_wrapped(&["apples", "bananas"], 18),
+            "apples and bananas"
+        );
+        assert_eq!(humanise_list_wrapped(&["a", "b", "c"], 0), "a,\nb,\nand c");
+        // Widths are measured in characters, not bytes.
+        assert_eq!(
+            humanise_list_wrapped(&["éé", "üü", "öö"], 12),
+            "éé, üü,\nand öö"
+        );
+        for width in 0..40 {
+            let wrapped = humanise_list_wrapped(&["apples", "bananas", "cherries"], width);
+            assert_eq!(
+                wrapped.replace('\n', " "),
+                humanise_list(&["apples", "bananas", "cherries"])
+            );
+        }
+    }
+
+    #[test]
+    fn list_format_default() {
+        let format = ListFormat::default();
+        for list in [
+            &["a"][..],
+            &["a", "b"],
+            &["a", "b", "c"],
+            &["a", "b", "c", "d"],
+        ] {
+            assert_eq!(format.format(list), humanise_list(list));
+        }
+    }
+    #[test]
+    fn list_format_pair_conjunction() {
+        let format = ListFormat::new().pair_conjunction("&").conjunction("or");
+        assert_eq!(format.format(&["a", "b"]), "a & b");
+        assert_eq!(format.format(&["a", "b", "c"]), "a, b, or c");
+    }
+
+    #[test]
+    fn list_with_conjunction() {
+        assert_eq!(humanise_list_with::<&str>(&[], "or"), "");
+        assert_eq!(humanise_list_with(&["a", "b"], "nor"), "a nor b");
+        assert_eq!(
+            humanise_list_with(&["a", "b", "c", "d"], "nor"),
+            "a, b, c, nor d"
+        );
+    }
+
+    #[test]
+    fn list_oxford_comma() {
+        assert_eq!(humanise_list(&["a", "b", "c"]), "a, b, and c");
+        let format = ListFormat::new().oxford_comma(false);
+        assert_eq!(format.format(&["a", "b"]), "a and b");
+        assert_eq!(format.format(&["a", "b", "c"]), "a, b and c");
+        assert_eq!(format.format(&["a", "b", "c", "d"]), "a, b, c and d");
+    }
+
+    #[test]
+    fn iter() {
+        assert_eq!(humanise_iter(core::iter::empty::<u8>()), "");
+        assert_eq!(humanise_iter(vec!["a".to_string()]), "a");
+        assert_eq!(
+            humanise_iter(["a", "b"].iter().map(|s| s.to_uppercase())),
+            "A and B"
+        );
+    }
+
+    #[test]
+    fn list_truncated() {
+        assert_eq!(humanise_list_truncated::<&str>(&[], 0), "");
+        assert_eq!(humanise_list_truncated(&["a"], 0), "1 other");
+        assert_eq!(humanise_list_truncated(&["a", "b"], 1), "a and 1 other");
+        assert_eq!(humanise_list_truncated(&["a", "b"], 2), "a and b");
+        let format = ListFormat::new().conjunction("or").truncate(2);
+        assert_eq!(format.format(&[1, 2, 3, 4]), "1, 2, or 2 others");
+        assert_eq!(format.truncate(None).format(&[1, 2, 3, 4]), "1, 2, 3, or 4");
+    }
+
+    #[test]
+    fn pluralise_regular() {
+        assert_eq!(pluralise(0, "name"), "names");
+        assert_eq!(pluralise(1, "bus"), "bus");
+        assert_eq!(pluralise(2, "bus"), "buses");
+        assert_eq!(pluralise(2, "buzz"), "buzzes");
+        assert_eq!(pluralise(2, "church"), "churches");
+        assert_eq!(pluralise(2, "bush"), "bushes");
+        assert_eq!(pluralise(2, "key"), "keys");
+        assert_eq!(pluralise(2, "y"), "ys");
+        assert_eq!(pluralise(2, "Party"), "Parties");
+    }
+    #[test]
+    fn pluralise_f_to_ves() {
+        assert_eq!(pluralise_with(2, "wife", true), "wives");
+        assert_eq!(pluralise_with(2, "wolf", true), "wolves");
+        assert_eq!(pluralise_with(2, "wolf", false), "wolfs");
+        assert_eq!(pluralise_with(2, "cat", true), "cats");
+    }
+
+    #[test]
+    fn pluralise_irregular() {
+        assert_eq!(pluralise(2, "person"), "people");
+        assert_eq!(pluralise(1, "person"), "person");
+        assert_eq!(pluralise(2, "Child"), "Children");
+        assert_eq!(pluralise(2, "FOOT"), "FEET");
+        assert_eq!(pluralise(0, "sheep"), "sheep");
+    }
+    #[test]
+    fn pluraliser() {
+        let mut pluraliser = Pluraliser::new().irregular("Cactus", "cacti");
+        pluraliser.insert("person", "persons");
+        assert_eq!(pluraliser.pluralise(2, "cactus"), "cacti");
+        assert_eq!(pluraliser.pluralise(2, "Cactus"), "Cacti");
+        assert_eq!(pluraliser.pluralise(2, "person"), "persons");
+        assert_eq!(pluraliser.pluralise(2, "mouse"), "mice");
+        assert_eq!(pluraliser.pluralise(2, "box"), "boxes");
+        assert_eq!(pluraliser.pluralise(2, "wife"), "wifes");
+    }
+
+    #[test]
+    fn number_words() {
+        assert_eq!(number_to_words(7), "seven");
+        assert_eq!(number_to_words(15), "fifteen");
+        assert_eq!(number_to_words(40), "forty");
+        assert_eq!(number_to_words(99), "ninety-nine");
+        assert_eq!(number_to_words(100), "one hundred");
+        assert_eq!(number_to_words(1_000_000), "one million");
+        assert_eq!(number_to_words(1_001_001), "one million one thousand one");
+        assert_eq!(
+            number_to_words(1_000_001_000_000),
+            "one trillion one million"
+        );
+        assert_eq!(number_to_words_with(1_000, true), "one thousand");
+        assert_eq!(
+            number_to_words_with(1_100_050, true),
+            "one million one hundred thousand and fifty"
+        );
+        assert!(number_to_words(u128::MAX)
+            .starts_with("three hundred forty undecillion two hundred eighty-two decillion"));
+        assert!(number_to_words(u128::MAX).ends_with("four hundred fifty-five"));
+    }
+
+    #[test]
+    fn count_abbreviations() {
+        assert_eq!(humanise_count(0), "0");
+        assert_eq!(humanise_count(1000), "1K");
+        assert_eq!(humanise_count(1049), "1K");
+        assert_eq!(humanise_count(1050), "1.1K");
+        assert_eq!(humanise_count(999_949), "999.9K");
+        assert_eq!(humanise_count(999_950), "1M");
+        assert_eq!(humanise_count(1_500_000_000_000), "1.5T");
+        assert_eq!(humanise_count(4_200_000_000_000_000), "4.2Q");
+        assert_eq!(humanise_count(12_000_000_000_000_000_000), "12000Q");
+        assert_eq!(humanise_count_with(1_000_000, 3, false), "1.000M");
+        assert_eq!(
+            humanise_count_with(1_500, 20, false),
+            "1.50000000000000000000K"
+        );
+        assert!(humanise_count_with(u128::MAX, 20, true).ends_with('Q'));
+    }
+
+    #[test]
+    fn humanise_trait() {
+        fn humanised<T: Humanise + ?Sized>(value: &T) -> String {
+            value.humanise()
+        }
+        let empty: Vec<u8> = vec![];
+        assert_eq!(humanised(&empty), "");
+        assert_eq!(humanised(&["a"][..]), "a");
+        assert_eq!(humanised(&vec!["a", "b"]), "a and b");
+    }
+
+    #[test]
+    fn list_format_skip_blank() {
+        let format = ListFormat::new().skip_blank(true);
+        assert_eq!(format.format(&["", " ", "\t\n"]), "");
+        assert_eq!(format.format(&[" a ", "  ", "b", "", "c"]), " a , b, and c");
+        assert_eq!(
+            format.truncate(1).format(&["", "a", "", "b", "c"]),
+            "a and 2 others"
+        );
+        assert_eq!(ListFormat::new().format(&["a", "", "c"]), "a, , and c");
+    }
+
+    #[test]
+    fn articles() {
+        assert_eq!(article(""), "a");
+        assert_eq!(article("Elephant"), "an");
+        assert_eq!(article("HOURS"), "an");
+        assert_eq!(article("umbrella"), "an");
+        assert_eq!(article("unimportant"), "an");
+        assert_eq!(article("useful"), "a");
+        assert_eq!(article("European"), "a");
+        assert_eq!(article("house"), "a");
+        assert_eq!(with_article("unit"), "a unit");
+        assert_eq!(
+            humanise_list_with_articles(&["hour", "user", "idea"]),
+            "an hour, a user, and an idea"
+        );
+    }
+
+    #[test]
+    fn list_format_overflow() {
+        let format = ListFormat::new()
+            .truncate(1)
+            .overflow(|remaining| format!("{} autres", remaining))
+            .conjunction("et");
+        assert_eq!(format.format(&["a", "b"]), "a et 1 autres");
+        assert_eq!(format.format(&["a", "b", "c"]), "a et 2 autres");
+        assert_eq!(format.format(&["a"]), "a");
+        assert_eq!(format.clone(), format);
+        assert_ne!(
+            format,
+            ListFormat::new()
+                .truncate(1)
+                .conjunction("et")
+                .overflow(|_| "".to_string())
+        );
+        assert_eq!(
+            ListFormat::new()
+                .truncate(0)
+                .overflow(|n| n.to_string())
+                .format(&[1, 2, 3]),
+            "3"
+        );
+    }
+
+    #[test]
+    fn list_format_truncated() {
+        let format = ListFormat::new().truncate(1).skip_blank(true);
+        let truncated = format.format_truncated(&["a", "", "b", " ", "c"]);
+        assert_eq!(truncated.text, "a and 2 others");
+        assert_eq!(truncated.hidden, 2);
+        assert_eq!(
+            ListFormat::new().format_truncated(&["a", "b"]),
+            TruncatedList {
+                text: "a and b".to_string(),
+                hidden: 0
+            }
+        );
+        assert_eq!(
+            ListFormat::new()
+                .truncate(0)
+                .format_truncated(&[1, 2])
+                .hidden,
+            2
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn list_wrapped_unicode() {
+        // Combining accents take up no extra space.
+        assert_eq!(
+            humanise_list_wrapped(
+                &["e\u{301}e\u{301}", "u\u{308}u\u{308}", "o\u{308}o\u{308}"],
+                12
+            ),
+            "e\u{301}e\u{301}, u\u{308}u\u{308},\nand o\u{308}o\u{308}"
+        );
+        // Wide characters take up two columns each.
+        assert_eq!(
+            humanise_list_wrapped(&["日本", "中国"], 12),
+            "日本\nand 中国"
+        );
+        assert_eq!(
+            humanise_list_wrapped(&["日本", "中国"], 13),
+            "日本 and 中国"
+        );
+        assert_eq!(text_width("👩‍👩‍👧"), 2);
+    }
 }